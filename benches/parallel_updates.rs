@@ -0,0 +1,71 @@
+//! measures the throughput of progress updates from many threads while the bars are rendered
+//!
+//! run with: `cargo bench --bench parallel_updates`
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use zenity::progress::{Frames, ProgressBar};
+
+const THREADS: usize = 8;
+const UPDATES_PER_THREAD: usize = 1_000_000;
+
+fn main() {
+    let total = THREADS * UPDATES_PER_THREAD;
+
+    let progress = ProgressBar::new(Frames::default());
+    let uid = progress.get_last();
+    progress.set_goal(&uid, &total);
+    progress.run_all();
+
+    let by_uid = measure(|| {
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..UPDATES_PER_THREAD {
+                        progress.inc(&uid, &1);
+                    }
+                });
+            }
+        });
+    });
+
+    let uid = progress.add(Frames::hash());
+    progress.set_goal(&uid, &total);
+    let handle = progress.handle(&uid);
+
+    let by_handle = measure(|| {
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..UPDATES_PER_THREAD {
+                        handle.inc(&1);
+                    }
+                });
+            }
+        });
+    });
+
+    assert_eq!(handle.get(), Some(total));
+    drop(progress);
+
+    report("ProgressBar::inc", total, by_uid);
+    report("ProgressHandle::inc", total, by_handle);
+}
+
+fn measure(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn report(name: &str, updates: usize, elapsed: Duration) {
+    println!(
+        "{:<20} {} updates from {} threads in {:?} ({:.1} M updates/s)",
+        name,
+        updates,
+        THREADS,
+        elapsed,
+        updates as f64 / elapsed.as_secs_f64() / 1_000_000.0
+    );
+}
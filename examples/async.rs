@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use zenity::spinner::{Frames, MultiSpinner};
+
+#[tokio::main]
+async fn main() {
+    let spinner = MultiSpinner::new();
+
+    let download = spinner.handle(&spinner.add(Frames::dot_spinner1()));
+    let build = spinner.handle(&spinner.add(Frames::dot_spinner2()));
+
+    download.set_text("Downloading...".to_string());
+    build.set_text("Building...".to_string());
+
+    let render = spinner.run_async();
+
+    let (download_result, build_result) = tokio::join!(
+        download.wrap(async {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            Ok::<_, String>("archive.tar.gz")
+        }),
+        build.wrap(async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Err::<(), _>("Build failed!".to_string())
+        }),
+    );
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    // wait for the spinners to be cleared before printing
+    drop(spinner);
+    render.await.unwrap();
+
+    println!("download: {:?}", download_result);
+    println!("build: {:?}", build_result);
+}
@@ -12,6 +12,7 @@ fn main() {
         speed_ms: 150, // custom speed for animation in milliseconds
         text: StyledString::default(),
         stop: false,
+        ..Default::default()
     };
 
     // create a MultiSpinner instance using the new custom animation
@@ -0,0 +1,27 @@
+use zenity::menu::select::FuzzySelect;
+
+fn main() {
+    println!("\n\nFuzzy Select Preview:");
+
+    let packages = vec![
+        "serde",
+        "serde_json",
+        "tokio",
+        "tokio-util",
+        "crossterm",
+        "regex",
+        "rayon",
+        "unicode-width",
+        "unicode-segmentation",
+        "lazy_static",
+        "chrono",
+        "ctrlc",
+    ];
+
+    // type to filter, e.g. "uw" for unicode-width
+    let package = FuzzySelect::new("Package:", packages)
+        .start_value()
+        .to_string();
+
+    println!("Package:  {:?}", package);
+}
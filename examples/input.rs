@@ -20,8 +20,31 @@ fn main() {
     .allow_force()
     .set_default("ABC")
     .start();
+    let username = Input::new(
+        "Enter a Username:",
+        Requirements::regex(Regex::new(r"^\w+$").unwrap())
+            .set_note("", "Only letters, digits and _ are allowed")
+            .add_custom(|name| {
+                if ["admin", "root"].contains(&name) {
+                    Err(format!("{} is already taken", name))
+                } else {
+                    Ok(())
+                }
+            }),
+    )
+    .start();
+    let port = Input::<u16>::typed("Enter a Port (1024-65535):")
+        .set_range(1024..)
+        .set_default("8080")
+        .start();
+    let branch = Input::<String>::typed("Enter a Branch:")
+        .set_completer(vec!["main", "develop", "feature/input", "feature/select"])
+        .start();
 
     println!("Existing Path:  {:?}", path);
     println!("Path with Regex:  {:?}", path_with_regex);
     println!("Regex:  {:?}", regex);
+    println!("Username:  {:?}", username);
+    println!("Port:  {:?}", port);
+    println!("Branch:  {:?}", branch);
 }
@@ -0,0 +1,15 @@
+use zenity::menu::input::Input;
+
+fn main() {
+    println!("\n\nLine Editing Preview:");
+    println!("- type \"fe\" and press Tab to complete up to \"feature/\"");
+    println!("- pick a branch with Up/Down, or keep typing");
+    println!("- move with Left/Right, Home/End or Alt+B/Alt+F and edit in the middle of the line");
+    println!("- Ctrl+W deletes the word before the cursor, Ctrl+U the whole line before it");
+
+    let branch = Input::<String>::typed("Enter a Branch:")
+        .set_completer(vec!["main", "develop", "feature/input", "feature/select"])
+        .start();
+
+    println!("Branch:  {:?}", branch);
+}
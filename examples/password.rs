@@ -0,0 +1,17 @@
+use zenity::menu::input::Password;
+
+fn main() {
+    println!("\n\nPassword Preview:");
+
+    let token = Password::new("API token:").hide_input().start();
+
+    let password = Password::new("New password:")
+        .set_mask('•')
+        .with_confirmation("Repeat password:")
+        .set_mismatch_note("Passwords do not match")
+        .start();
+
+    // both values are zeroed in memory when dropped
+    println!("Token length:  {}", token.len());
+    println!("Password length:  {}", password.chars().count());
+}
@@ -0,0 +1,25 @@
+use std::thread;
+use std::time::Duration;
+
+use rand::Rng;
+use rayon::prelude::*;
+
+use zenity::progress::ParallelProgressIterator;
+
+fn main() {
+    let files = (0..500)
+        .map(|n| format!("file_{}.txt", n))
+        .collect::<Vec<_>>();
+
+    let total_size: usize = files
+        .par_iter()
+        .progress()
+        .map(|name| {
+            // simulate processing the file
+            thread::sleep(Duration::from_millis(rand::thread_rng().gen_range(1..=40)));
+            name.len()
+        })
+        .sum();
+
+    println!("processed {} files ({} bytes)", files.len(), total_size);
+}
@@ -0,0 +1,27 @@
+use zenity::menu::select::{MultiSelect, Select};
+
+fn main() {
+    println!("\n\nSelect Preview:");
+
+    let color = *Select::new("Pick a color:", vec!["red", "green", "blue"])
+        .set_default(1)
+        .start_value();
+
+    // long lists scroll, use PageUp/PageDown to move faster
+    let number = Select::new("Pick a number:", 1..=100)
+        .set_page_size(5)
+        .start();
+
+    let toppings = MultiSelect::new(
+        "Pick 1-3 toppings (Space to toggle, a for all):",
+        vec!["cheese", "ham", "olives", "onions", "peppers"],
+    )
+    .set_defaults(&[0])
+    .set_min(1)
+    .set_max(3);
+    let toppings = toppings.start_values();
+
+    println!("Color:  {:?}", color);
+    println!("Number:  {:?}", number + 1);
+    println!("Toppings:  {:?}", toppings);
+}
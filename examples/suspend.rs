@@ -0,0 +1,26 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use zenity::menu::input::Confirm;
+use zenity::spinner::{Frames, MultiSpinner};
+
+fn main() {
+    let spinner = MultiSpinner::new();
+    let download = spinner.add(Frames::dot_spinner1());
+    spinner.set_text(&download, "Downloading...".to_string());
+
+    spinner.run_all();
+    sleep(Duration::from_secs(2));
+
+    // hide the spinner while asking the user
+    let overwrite = spinner.suspend(|| Confirm::new("Overwrite existing files?", true).start());
+
+    spinner.set_text(
+        &download,
+        format!("Installing (overwrite: {})...", overwrite),
+    );
+    sleep(Duration::from_secs(2));
+
+    spinner.succeed(&download);
+    sleep(Duration::from_millis(500));
+}
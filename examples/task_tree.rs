@@ -0,0 +1,49 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use zenity::spinner::{Frames, MultiSpinner, StepCounter};
+
+fn main() {
+    let spinner = MultiSpinner::new();
+
+    let build = spinner.add(Frames::dot_spinner1().show_elapsed());
+    let compile = spinner.add_child(&build, Frames::dot_spinner2().show_elapsed());
+    let codegen = spinner.add_child(&compile, Frames::dot_spinner3().show_elapsed());
+    let link = spinner.add_child(&build, Frames::dot_spinner4().show_elapsed());
+
+    let test = spinner.add(Frames::dot_spinner1().show_elapsed());
+    let unit = spinner.add_child(&test, Frames::dot_spinner2().show_elapsed());
+    let integration = spinner.add_child(&test, Frames::dot_spinner3().show_elapsed());
+
+    spinner.set_text(&build, "Building".to_string());
+    spinner.set_text(&compile, "Compiling".to_string());
+    spinner.set_text(&codegen, "Generating code".to_string());
+    spinner.set_text(&link, "Linking".to_string());
+    spinner.set_text(&test, "Testing".to_string());
+    spinner.set_text(&unit, "Unit tests".to_string());
+    spinner.set_text(&integration, "Integration tests".to_string());
+
+    spinner.step_counter(StepCounter::new().set_format("step {step}/{total}"));
+    spinner.run_all();
+
+    sleep(Duration::from_secs(2));
+    spinner.succeed(&codegen);
+
+    sleep(Duration::from_secs(1));
+    spinner.succeed(&compile);
+
+    sleep(Duration::from_secs(2));
+    spinner.succeed(&link);
+    spinner.succeed(&unit);
+
+    sleep(Duration::from_secs(1));
+    // the children collapse once their parent finishes
+    spinner.succeed(&build);
+
+    sleep(Duration::from_secs(2));
+    // a failed child marks the parent as failed as well
+    spinner.fail(&integration);
+    spinner.stop(&test);
+
+    sleep(Duration::from_secs(2));
+}
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+use std::thread::sleep;
+use std::time::Duration;
+
+use zenity::spinner::{Frames, MultiSpinner};
+
+fn main() {
+    let theme: HashMap<String, Frames> = zenity::theme::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/examples/spinners.toml"
+    ))
+    .expect("failed to load the theme");
+
+    let spinner = MultiSpinner::new();
+
+    let mut names = theme.keys().collect::<Vec<_>>();
+    names.sort();
+
+    for name in names {
+        let uid = spinner.add(theme[name].clone());
+        spinner.set_text(&uid, name.to_string());
+    }
+
+    spinner.run_all();
+
+    sleep(Duration::from_secs(5));
+}
@@ -135,8 +135,6 @@ impl CliColorConfig {
         }
     }
 
-    /// parse args to check for --color=always|auto|never
-
     /// parse args to check for --color=always|auto|never
     fn parse_arguments(args: &[String]) -> ColorOption {
         for arg in args.iter() {
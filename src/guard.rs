@@ -0,0 +1,158 @@
+//! Opt-in guard restoring the terminal on panic and Ctrl+C
+//!
+//! While a spinner or progress bar is running the cursor is hidden and menus enable raw mode.
+//! If the program panics or gets interrupted, the terminal would be left in that state.
+//!
+//! After calling [`install`] a panic hook and a Ctrl+C (SIGINT) handler are registered that:
+//!
+//! - clear the live region of running spinners/progress bars and move below it
+//! - disable raw mode
+//! - show the cursor again
+//!
+//! ```
+//! use zenity::guard;
+//! use zenity::spinner::MultiSpinner;
+//!
+//! // install once at the start of the program
+//! guard::install();
+//!
+//! let spinner = MultiSpinner::default();
+//!
+//! // a panic or Ctrl+C from here on will leave the terminal in a usable state
+//! ```
+
+use std::panic;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "menu")]
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::terminal::console_guard;
+
+/// exit code used after an interrupt (128 + SIGINT)
+const INTERRUPT_EXIT_CODE: i32 = 130;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// installs the panic hook and the Ctrl+C handler
+///
+/// the previous panic hook is still called after the terminal was restored,
+/// so panic messages are printed as usual
+///
+/// calling this more than once has no effect
+///
+/// **Note:**
+/// - if another Ctrl+C handler was already set (e.g. using the `ctrlc` crate)
+///   only the panic hook is installed
+///
+/// # Example
+///
+/// ```
+/// use zenity::guard;
+///
+/// guard::install();
+/// # assert!(guard::is_installed());
+/// ```
+pub fn install() {
+    if INSTALLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        console_guard::restore();
+        previous_hook(info);
+    }));
+
+    let _ = ctrlc::set_handler(interrupt);
+}
+
+/// returns true if [`install`] was called
+///
+/// # Example
+///
+/// ```
+/// use zenity::guard;
+///
+/// guard::install();
+/// assert!(guard::is_installed());
+/// ```
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::SeqCst)
+}
+
+/// restores the terminal manually
+///
+/// shows the cursor, disables raw mode and clears the live region if one is active
+///
+/// # Example
+///
+/// ```
+/// use zenity::guard;
+///
+/// guard::restore();
+/// ```
+pub fn restore() {
+    console_guard::restore();
+}
+
+/// restores the terminal and exits with the interrupt exit code
+fn interrupt() {
+    console_guard::restore();
+    process::exit(INTERRUPT_EXIT_CODE);
+}
+
+/// handles Ctrl+C key events read while raw mode is enabled
+///
+/// raw mode disables the SIGINT generation of the terminal, so menus reading key events
+/// have to forward them here to behave like the signal handler
+#[cfg(feature = "menu")]
+pub(crate) fn handle_interrupt_event(event: &Event) {
+    if is_installed() && is_interrupt_event(event) {
+        interrupt();
+    }
+}
+
+#[cfg(feature = "menu")]
+#[inline]
+fn is_interrupt_event(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Key(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) if modifiers.contains(KeyModifiers::CONTROL)
+    )
+}
+
+#[cfg(all(test, feature = "menu"))]
+mod tests {
+    use crossterm::event::KeyEventState;
+
+    use super::*;
+
+    #[test]
+    fn test_is_interrupt_event_ctrl_c() {
+        let event = Event::Key(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        });
+        assert!(is_interrupt_event(&event));
+    }
+
+    #[test]
+    fn test_is_interrupt_event_plain_c() {
+        let event = Event::Key(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers: KeyModifiers::empty(),
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        });
+        assert!(!is_interrupt_event(&event));
+    }
+}
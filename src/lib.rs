@@ -53,14 +53,22 @@
 //!
 //! - `Always`: Enable color output regardless of the terminal type and capabilities
 //! - `auto`:
-//! Automatically determine
-//! whether to enable color output based on the terminal type and capabilities.
-//! If stdout is a pipe or if the terminal doesn't support colors, colors will be disabled
+//!   Automatically determine
+//!   whether to enable color output based on the terminal type and capabilities.
+//!   If stdout is a pipe or if the terminal doesn't support colors, colors will be disabled
 //! - `never`: Disable color output
 //!
 //! **Note**: If the stdout is a pipe or if the terminal doesn't support colors,
 //! colors will be automatically disabled
 //!
+//! # Synchronized Output
+//!
+//! On terminals known to support synchronized output (DEC mode 2026), like kitty, WezTerm or foot,
+//! each frame of spinners and progress bars is drawn atomically to prevent flickering
+//!
+//! Set `ZENITY_SYNC_OUTPUT=0` to disable it,
+//! or use `synchronized_output(false)` on a spinner or progress bar
+//!
 //! Check out the examples for more
 //!
 //! ## Feature Requests and Bug Reports
@@ -80,7 +88,11 @@
 
 // Helper
 pub mod color;
+pub mod guard;
+pub mod sort;
 pub mod style;
+#[cfg(feature = "serde")]
+pub mod theme;
 
 // Main features
 #[cfg(feature = "menu")]
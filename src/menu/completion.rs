@@ -0,0 +1,533 @@
+//! Completion for [`Input`](super::input::Input)
+//!
+//! while typing, the candidates of a [`Completer`] are shown as grey ghost text behind the
+//! cursor and, if there are several, as a dropdown list below the input
+//!
+//! | key                 | action                                                  |
+//! |---------------------|---------------------------------------------------------|
+//! | `Tab`               | insert the ghost text, then cycle through the dropdown  |
+//! | `→` (end of line)   | insert the ghost text                                   |
+//! | `↓` / `↑`           | select the next / previous candidate of the dropdown    |
+//!
+//! inputs with a path requirement complete paths with a [`PathCompleter`] by default
+//!
+//! # Example
+//!
+//! ```no_run
+//! use zenity::menu::input::Input;
+//!
+//! let branches = vec!["main", "develop", "feature/completion"];
+//!
+//! let branch = Input::<String>::typed("Branch:")
+//!     .set_completer(branches)
+//!     .start();
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+
+use crate::menu::line::LineBuffer;
+use crate::style::{combine_attributes, Attribute, Color, ContentStyle, StyledString};
+
+/// maximum number of candidates listed in the dropdown
+const DROPDOWN_SIZE: usize = 5;
+
+/// a possible completion of the text before the cursor
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    /// replaces the text before the cursor
+    pub value: String,
+    /// shown in the dropdown list
+    pub label: String,
+}
+
+impl Candidate {
+    /// creates a candidate labeled with its value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::completion::Candidate;
+    ///
+    /// let candidate = Candidate::new("origin/main");
+    /// # assert_eq!(candidate.label, "origin/main");
+    /// ```
+    pub fn new(value: &str) -> Self {
+        Candidate {
+            value: value.to_string(),
+            label: value.to_string(),
+        }
+    }
+
+    /// sets the text shown in the dropdown list
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::completion::Candidate;
+    ///
+    /// let candidate = Candidate::new("ssh example.com").set_label("example.com (last used)");
+    /// ```
+    pub fn set_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+}
+
+impl From<&str> for Candidate {
+    fn from(value: &str) -> Self {
+        Candidate::new(value)
+    }
+}
+
+impl From<String> for Candidate {
+    fn from(value: String) -> Self {
+        Candidate::new(&value)
+    }
+}
+
+/// source of completions for an [`Input`](super::input::Input)
+///
+/// implemented for closures taking the line and cursor
+/// and for lists of strings, which complete the whole line by prefix
+///
+/// # Example
+///
+/// ```
+/// use zenity::menu::completion::{Candidate, Completer};
+///
+/// struct Hosts(Vec<String>);
+///
+/// impl Completer for Hosts {
+///     fn complete(&self, line: &str, cursor: usize) -> Vec<Candidate> {
+///         // complete the host after "user@"
+///         let (user, host) = line[..cursor].split_once('@').unwrap_or(("", &line[..cursor]));
+///
+///         self.0
+///             .iter()
+///             .filter(|known| known.starts_with(host))
+///             .map(|known| {
+///                 let value = if user.is_empty() { known.clone() } else { format!("{}@{}", user, known) };
+///                 Candidate::new(&value).set_label(known)
+///             })
+///             .collect()
+///     }
+/// }
+///
+/// let hosts = Hosts(vec!["alpha.example.com".to_string(), "beta.example.com".to_string()]);
+/// # assert_eq!(hosts.complete("me@b", 4)[0].value, "me@beta.example.com");
+/// ```
+pub trait Completer {
+    /// returns the candidates for `line` with the cursor at the byte index `cursor`
+    ///
+    /// the value of each candidate replaces the text before the cursor,
+    /// only values starting with that text are shown as ghost text
+    fn complete(&self, line: &str, cursor: usize) -> Vec<Candidate>;
+}
+
+impl<F> Completer for F
+where
+    F: Fn(&str, usize) -> Vec<Candidate>,
+{
+    fn complete(&self, line: &str, cursor: usize) -> Vec<Candidate> {
+        self(line, cursor)
+    }
+}
+
+impl<S: AsRef<str>> Completer for Vec<S> {
+    fn complete(&self, line: &str, cursor: usize) -> Vec<Candidate> {
+        self.iter()
+            .map(AsRef::as_ref)
+            .filter(|item| item.starts_with(&line[..cursor]))
+            .map(Candidate::new)
+            .collect()
+    }
+}
+
+/// completes the last segment of a path against the filesystem
+///
+/// relative paths are resolved against the base directory (the working directory by default),
+/// directories get a trailing `/` and hidden entries are only suggested
+/// if the segment starts with a `.`
+///
+/// # Example
+///
+/// ```
+/// use zenity::menu::completion::{Completer, PathCompleter};
+///
+/// let completer = PathCompleter::new().set_base_dir(env!("CARGO_MANIFEST_DIR"));
+/// # assert!(completer.complete("Cargo.t", 7).iter().any(|candidate| candidate.value == "Cargo.toml"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PathCompleter {
+    base_dir: Option<PathBuf>,
+}
+
+impl PathCompleter {
+    /// creates a completer resolving relative paths against the working directory
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// resolves relative paths against `base_dir` instead of the working directory
+    pub fn set_base_dir(mut self, base_dir: impl AsRef<Path>) -> Self {
+        self.base_dir = Some(base_dir.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl Completer for PathCompleter {
+    fn complete(&self, line: &str, cursor: usize) -> Vec<Candidate> {
+        let input = &line[..cursor];
+        let split = input
+            .rfind(['/', MAIN_SEPARATOR])
+            .map_or(0, |index| index + 1);
+        let (directory, segment) = input.split_at(split);
+
+        let search = match (directory, &self.base_dir) {
+            ("", Some(base_dir)) => base_dir.clone(),
+            ("", None) => PathBuf::from("."),
+            (directory, Some(base_dir)) => base_dir.join(directory),
+            (directory, None) => PathBuf::from(directory),
+        };
+
+        let Ok(entries) = fs::read_dir(search) else {
+            return Vec::new();
+        };
+
+        let mut candidates = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+
+                if !name.starts_with(segment)
+                    || (name.starts_with('.') && !segment.starts_with('.'))
+                {
+                    return None;
+                }
+
+                // follow symlinks, so links to directories complete like directories
+                let label = if entry.path().is_dir() {
+                    format!("{}/", name)
+                } else {
+                    name
+                };
+
+                Some(Candidate {
+                    value: format!("{}{}", directory, label),
+                    label,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|a, b| a.label.cmp(&b.label));
+        candidates
+    }
+}
+
+/// the longest prefix shared by all candidates
+fn common_prefix(candidates: &[Candidate]) -> &str {
+    let Some((first, rest)) = candidates.split_first() else {
+        return "";
+    };
+
+    let mut prefix = first.value.as_str();
+    for candidate in rest {
+        let length = prefix
+            .char_indices()
+            .zip(candidate.value.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(
+                prefix.len().min(candidate.value.len()),
+                |((index, _), _)| index,
+            );
+        prefix = &prefix[..length];
+    }
+
+    prefix
+}
+
+/// completion state kept between key presses
+#[derive(Debug, Default)]
+pub(crate) struct Completion {
+    /// candidates for the current input
+    candidates: Vec<Candidate>,
+    /// index of the inserted candidate while cycling through them
+    selected: Option<usize>,
+}
+
+impl Completion {
+    /// refreshes the candidates after the input changed
+    ///
+    /// nothing is suggested for an empty input
+    pub fn update(&mut self, buffer: &LineBuffer, completer: &dyn Completer) {
+        self.selected = None;
+        self.candidates = if buffer.is_empty() {
+            Vec::new()
+        } else {
+            completer.complete(buffer, buffer.before_cursor().len())
+        };
+    }
+
+    /// the text Tab inserts before the cursor
+    ///
+    /// - a single candidate
+    /// - the common prefix of multiple candidates, if it's longer than the input
+    /// - the first of multiple candidates otherwise
+    fn next_value(&self, buffer: &LineBuffer) -> Option<&str> {
+        if self.selected.is_some() {
+            return None;
+        }
+
+        match self.candidates.as_slice() {
+            [] => None,
+            [candidate] => Some(&candidate.value),
+            [first, ..] => {
+                let prefix = common_prefix(&self.candidates);
+
+                if prefix.len() > buffer.before_cursor().len() {
+                    Some(prefix)
+                } else {
+                    Some(&first.value)
+                }
+            }
+        }
+    }
+
+    /// the grey text shown behind the cursor, i.e. what Tab would insert
+    ///
+    /// only shown while the cursor is at the end of the line
+    pub fn ghost(&self, buffer: &LineBuffer) -> &str {
+        if buffer.before_cursor().len() != buffer.len() {
+            return "";
+        }
+
+        self.next_value(buffer)
+            .and_then(|value| value.strip_prefix(buffer.before_cursor()))
+            .unwrap_or_default()
+    }
+
+    /// completes the text before the cursor when Tab is pressed
+    ///
+    /// inserts the ghost text and refreshes the candidates,
+    /// if there is nothing left to insert, pressing Tab again cycles through the candidates
+    pub fn tab(&mut self, buffer: &mut LineBuffer, completer: &dyn Completer) {
+        // keep cycling while the inserted candidate wasn't edited
+        if let Some(selected) = self.selected {
+            if self.candidates[selected].value == buffer.before_cursor() {
+                self.cycle(buffer, true);
+                return;
+            }
+        }
+
+        let Some(value) = self.next_value(buffer).map(str::to_string) else {
+            return;
+        };
+
+        // the first candidate is inserted if the candidates share no longer prefix
+        let cycle = self.candidates.len() > 1
+            && common_prefix(&self.candidates).len() <= buffer.before_cursor().len();
+        buffer.replace_before_cursor(&value);
+
+        if cycle {
+            self.selected = Some(0);
+        } else {
+            self.update(buffer, completer);
+        }
+    }
+
+    /// inserts the next (or previous) candidate of the dropdown
+    pub fn cycle(&mut self, buffer: &mut LineBuffer, forward: bool) {
+        let count = self.candidates.len();
+        if count < 2 {
+            return;
+        }
+
+        let next = match (self.selected, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(selected), true) => (selected + 1) % count,
+            (Some(selected), false) => (selected + count - 1) % count,
+        };
+
+        buffer.replace_before_cursor(&self.candidates[next].value);
+        self.selected = Some(next);
+    }
+
+    /// whether multiple candidates are listed in a dropdown
+    pub fn has_dropdown(&self) -> bool {
+        self.candidates.len() > 1
+    }
+
+    /// the dropdown lines listing multiple candidates, the selected one highlighted
+    ///
+    /// the list scrolls to keep the selected candidate visible
+    pub fn dropdown(&self) -> Vec<Vec<StyledString>> {
+        if !self.has_dropdown() {
+            return Vec::new();
+        }
+
+        let selected = self.selected.unwrap_or(0);
+        let offset = (selected + 1).saturating_sub(DROPDOWN_SIZE);
+
+        let mut lines = self
+            .candidates
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(DROPDOWN_SIZE)
+            .map(|(index, candidate)| {
+                vec![if self.selected == Some(index) {
+                    StyledString::simple(
+                        &format!("> {}", candidate.label),
+                        Some(Color::Cyan),
+                        None,
+                        None,
+                    )
+                } else {
+                    StyledString::simple(
+                        &format!("  {}", candidate.label),
+                        Some(Color::DarkGrey),
+                        None,
+                        None,
+                    )
+                }]
+            })
+            .collect::<Vec<_>>();
+
+        let hidden = self.candidates.len() - lines.len();
+        if hidden > 0 {
+            lines.push(vec![StyledString {
+                string: format!("  +{} more", hidden),
+                style: ContentStyle {
+                    foreground_color: Some(Color::Grey),
+                    attributes: combine_attributes(&[&Attribute::Italic]),
+                    ..Default::default()
+                },
+            }]);
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu::temp_dir::TempDir;
+
+    /// a directory with a few files and directories to complete
+    fn project_dir(name: &str) -> TempDir {
+        let dir = TempDir::new(name);
+        fs::create_dir_all(dir.join("src/menu")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        fs::write(dir.join("src/style.rs"), "").unwrap();
+        fs::write(dir.join("src/.hidden"), "").unwrap();
+        dir
+    }
+
+    fn values(candidates: &[Candidate]) -> Vec<&str> {
+        candidates
+            .iter()
+            .map(|candidate| candidate.value.as_str())
+            .collect()
+    }
+
+    fn buffer(text: &str) -> LineBuffer {
+        let mut buffer = LineBuffer::new();
+        buffer.set(text);
+        buffer
+    }
+
+    #[test]
+    fn test_path_completer() {
+        let dir = project_dir("path-completer");
+        let completer = PathCompleter::new().set_base_dir(&dir);
+        let complete = |input: &str| completer.complete(input, input.len());
+
+        assert_eq!(
+            values(&complete("src/")),
+            vec!["src/lib.rs", "src/menu/", "src/style.rs"]
+        );
+        assert_eq!(complete("src/m")[0].label, "menu/");
+        assert_eq!(values(&complete("src/.")), vec!["src/.hidden"]);
+        assert!(complete("missing/").is_empty());
+
+        // only the text before the cursor is completed
+        assert_eq!(
+            values(&completer.complete("src/sx", 5)),
+            vec!["src/style.rs"]
+        );
+    }
+
+    #[test]
+    fn test_tab() {
+        let completer = vec!["src/lib.rs", "src/line.rs", "src/menu/"];
+
+        let mut completion = Completion::default();
+        let mut buffer = buffer("src/m");
+
+        completion.update(&buffer, &completer);
+        assert_eq!(completion.ghost(&buffer), "enu/");
+        completion.tab(&mut buffer, &completer);
+        assert_eq!(&*buffer, "src/menu/");
+        assert!(completion.dropdown().is_empty());
+
+        buffer.replace_before_cursor("src/l");
+        completion.update(&buffer, &completer);
+        assert_eq!(completion.ghost(&buffer), "i");
+        completion.tab(&mut buffer, &completer);
+        assert_eq!(&*buffer, "src/li");
+        assert_eq!(completion.dropdown().len(), 2);
+
+        // nothing left to complete, cycle
+        assert_eq!(completion.ghost(&buffer), "b.rs");
+        completion.tab(&mut buffer, &completer);
+        assert_eq!(&*buffer, "src/lib.rs");
+        assert_eq!(completion.ghost(&buffer), "");
+        completion.tab(&mut buffer, &completer);
+        assert_eq!(&*buffer, "src/line.rs");
+        completion.cycle(&mut buffer, false);
+        assert_eq!(&*buffer, "src/lib.rs");
+    }
+
+    #[test]
+    fn test_dropdown() {
+        let completer = (0..8).map(|n| format!("item{}", n)).collect::<Vec<_>>();
+
+        let mut completion = Completion::default();
+        let mut buffer = buffer("i");
+        completion.update(&buffer, &completer);
+
+        let dropdown = completion.dropdown();
+        assert_eq!(dropdown.len(), DROPDOWN_SIZE + 1);
+        assert_eq!(dropdown[0][0].string, "  item0");
+        assert_eq!(dropdown[DROPDOWN_SIZE][0].string, "  +3 more");
+
+        completion.cycle(&mut buffer, false);
+        assert_eq!(&*buffer, "item7");
+        assert_eq!(
+            completion.dropdown()[DROPDOWN_SIZE - 1][0].string,
+            "> item7"
+        );
+
+        // a single candidate isn't listed
+        completion.update(&buffer, &completer);
+        assert!(!completion.has_dropdown());
+        assert!(completion.dropdown().is_empty());
+    }
+
+    #[test]
+    fn test_closure_completer() {
+        let completer = |line: &str, _cursor: usize| vec![Candidate::from(line.to_uppercase())];
+
+        assert_eq!(values(&completer.complete("abc", 3)), vec!["ABC"]);
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        assert_eq!(common_prefix(&["añb".into(), "añc".into()]), "añ");
+        assert_eq!(common_prefix(&["ab".into(), "a".into()]), "a");
+        assert_eq!(common_prefix(&[]), "");
+    }
+}
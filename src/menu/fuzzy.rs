@@ -0,0 +1,147 @@
+//! fuzzy matching used to filter the options of a [`FuzzySelect`](super::select::FuzzySelect)
+
+/// score of every matched character
+const MATCH: i64 = 16;
+/// bonus for a character matched right after the previous one
+const CONSECUTIVE: i64 = 24;
+/// bonus for a character at the start of a word
+const WORD_START: i64 = 20;
+/// penalty for every character skipped between two matches
+const GAP: i64 = 2;
+
+/// a successful match of a pattern
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Match {
+    /// higher scores are better matches
+    pub score: i64,
+    /// indices of the matched chars of the text
+    pub indices: Vec<usize>,
+}
+
+/// matches `pattern` against `text`, ignoring case
+///
+/// all chars of the pattern have to appear in the text in the same order,
+/// matches at word starts and runs of consecutive chars score higher
+///
+/// # Returns
+///
+/// None if the text doesn't contain the pattern, an empty pattern matches everything
+pub(crate) fn fuzzy_match(pattern: &str, text: &str) -> Option<Match> {
+    let pattern = pattern
+        .chars()
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<_>>();
+    let original = text.chars().collect::<Vec<_>>();
+    // lower case chars can expand to multiple chars (e.g. 'İ'), so each keeps its original index
+    let (text, positions): (Vec<_>, Vec<_>) = original
+        .iter()
+        .enumerate()
+        .flat_map(|(index, c)| c.to_lowercase().map(move |lower| (lower, index)))
+        .unzip();
+
+    let Some(&first) = pattern.first() else {
+        return Some(Match {
+            score: 0,
+            indices: Vec::new(),
+        });
+    };
+
+    // match greedily from every possible start and keep the best result
+    (0..text.len())
+        .filter(|&start| text[start] == first)
+        .filter_map(|start| {
+            let mut indices = vec![start];
+
+            for &c in &pattern[1..] {
+                let from = indices.last().unwrap() + 1;
+                let offset = text[from..].iter().position(|&other| other == c)?;
+                indices.push(from + offset);
+            }
+
+            let mut indices = indices
+                .into_iter()
+                .map(|index| positions[index])
+                .collect::<Vec<_>>();
+            indices.dedup();
+
+            Some(Match {
+                score: score(&original, &indices),
+                indices,
+            })
+        })
+        .max_by_key(|found| (found.score, std::cmp::Reverse(found.indices[0])))
+}
+
+fn score(text: &[char], indices: &[usize]) -> i64 {
+    let mut score = 0;
+
+    for (position, &index) in indices.iter().enumerate() {
+        score += MATCH;
+
+        if is_word_start(text, index) {
+            score += WORD_START;
+        }
+
+        if position > 0 {
+            let previous = indices[position - 1];
+            if index == previous + 1 {
+                score += CONSECUTIVE;
+            } else {
+                score -= GAP * (index - previous - 1) as i64;
+            }
+        } else {
+            // prefer matches near the start of the text
+            score -= index as i64;
+        }
+    }
+
+    score
+}
+
+/// true for the first char, chars after a separator and upper case chars after lower case ones
+fn is_word_start(text: &[char], index: usize) -> bool {
+    match index.checked_sub(1).map(|previous| text[previous]) {
+        None => true,
+        Some(previous) => {
+            !previous.is_alphanumeric() || (previous.is_lowercase() && text[index].is_uppercase())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("fb", "foo-bar").unwrap().indices, vec![0, 4]);
+        assert_eq!(fuzzy_match("FOO", "xfoo").unwrap().indices, vec![1, 2, 3]);
+        assert_eq!(fuzzy_match("", "foo").unwrap().score, 0);
+        assert_eq!(fuzzy_match("of", "foo"), None);
+    }
+
+    #[test]
+    fn test_expanding_lower_case() {
+        // 'İ' lower cases to "i\u{307}" on both sides
+        assert_eq!(fuzzy_match("İs", "İstanbul").unwrap().indices, vec![0, 1]);
+        assert_eq!(fuzzy_match("is", "İSTANBUL").unwrap().indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_best_start() {
+        // the later "ma" of "main" is consecutive and at a word start
+        assert_eq!(
+            fuzzy_match("main", "feature/my-main").unwrap().indices,
+            vec![11, 12, 13, 14]
+        );
+    }
+
+    #[test]
+    fn test_ranking() {
+        let score = |text| fuzzy_match("mb", text).unwrap().score;
+
+        assert!(score("main-branch") > score("mainbranch"));
+        assert!(score("mainBranch") > score("mainbranch"));
+        assert!(score("mb") > score("main-branch"));
+    }
+}
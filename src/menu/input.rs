@@ -12,20 +12,36 @@
 //! # Examples
 //!
 
+use std::ffi::OsStr;
+use std::fmt::Display;
+use std::fs::{self, File, Metadata};
 use std::io;
-use std::path::Path;
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use crossterm::event::{Event, KeyCode, KeyEvent};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
 use crossterm::{
     cursor, execute,
-    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType},
+    terminal::{disable_raw_mode, enable_raw_mode},
 };
 use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use crate::color::ENABLE_COLOR;
-use crate::menu::handle_key_input;
-use crate::style::{Color, Print, SetForegroundColor};
-use crate::terminal::console_render::raw_mode_wrapper;
+use crate::guard;
+use crate::menu::completion::{Completer, Completion, PathCompleter};
+use crate::menu::line::LineBuffer;
+use crate::menu::{begin_region, end_region, handle_key_event, handle_key_input, note, read_event};
+use crate::style::{Color, Print, StyledString};
+use crate::terminal::console_render::LiveRegion;
+
+/// wrapper zeroing the value on drop, returned by [`Password::start`]
+pub use zeroize::Zeroizing;
+
+/// validates the input text, the error is displayed as the invalid note
+type Validator = Box<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
 
 /// Represents requirements for validating user input
 ///
@@ -48,13 +64,33 @@ pub struct Requirements {
 
     /// Allow creating the path if it doesn't exist yet
     ///
-    /// NOT WORKING yet will add asap
-    ///
     /// **NOTES**  
     /// - The `regex` still needs to match
     /// - This only works if `path` is true
+    /// - The parent directory has to exist
     allow_creating: bool,
 
+    /// If the path needs to be a file or a directory
+    kind: Option<PathKind>,
+
+    /// If the path needs to be readable
+    readable: bool,
+
+    /// If the path (or its parent if it doesn't exist yet) needs to be writable
+    writable: bool,
+
+    /// If the path needs to be executable
+    executable: bool,
+
+    /// Allowed extensions of the path, any if empty
+    extensions: Vec<String>,
+
+    /// Directory relative paths are resolved against, the working directory if None
+    base_dir: Option<PathBuf>,
+
+    /// Custom validators, checked after the `path` and `regex` conditions
+    validators: Vec<Validator>,
+
     /// Note to display if the condition matches
     true_note: Option<String>,
 
@@ -62,6 +98,13 @@ pub struct Requirements {
     false_note: Option<String>,
 }
 
+/// kinds of paths a path requirement can be restricted to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PathKind {
+    File,
+    Directory,
+}
+
 impl Default for Requirements {
     /// Creates default requirements for validating paths.
     ///
@@ -95,6 +138,13 @@ impl Requirements {
             regex: Some(regex),
             path: false,
             allow_creating: false,
+            kind: None,
+            readable: false,
+            writable: false,
+            executable: false,
+            extensions: Vec::new(),
+            base_dir: None,
+            validators: Vec::new(),
             true_note: None,
             false_note: None,
         }
@@ -102,6 +152,12 @@ impl Requirements {
 
     /// Creates requirements for validating paths
     ///
+    /// By default the path has to exist,
+    /// it can be restricted further with the `must_be_*`, `set_extensions` and `allow_creation` methods
+    ///
+    /// Inputs with a path requirement complete paths with Tab,
+    /// pressing Tab again cycles through ambiguous candidates listed below the input
+    ///
     /// # Examples
     ///
     /// ```
@@ -115,11 +171,86 @@ impl Requirements {
             regex: None,
             path: true,
             allow_creating: false,
+            kind: None,
+            readable: false,
+            writable: false,
+            executable: false,
+            extensions: Vec::new(),
+            base_dir: None,
+            validators: Vec::new(),
+            true_note: None,
+            // the failed path check provides a specific note
+            false_note: None,
+        }
+    }
+
+    /// Creates requirements validated by a closure
+    ///
+    /// The closure returns `Err` with the note to display if the input is invalid,
+    /// more validators or a regex can be added to the same requirements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::menu::input::Requirements;
+    ///
+    /// let taken = ["admin", "root"];
+    ///
+    /// let username = Requirements::custom(move |name| {
+    ///     if taken.contains(&name) {
+    ///         Err(format!("{} is already taken", name))
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// ```
+    pub fn custom<F>(validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Requirements {
+            regex: None,
+            path: false,
+            allow_creating: false,
+            kind: None,
+            readable: false,
+            writable: false,
+            executable: false,
+            extensions: Vec::new(),
+            base_dir: None,
+            validators: vec![Box::new(validator)],
             true_note: None,
-            false_note: Some("Please enter a valid path!".to_string()),
+            false_note: None,
         }
     }
 
+    /// Adds a validator to the requirements, see [`Requirements::custom`]
+    ///
+    /// Validators only run if the input passes the `path` and `regex` conditions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::menu::input::Requirements;
+    ///
+    /// // an existing path without spaces
+    /// let path = Requirements::path().add_custom(|path| {
+    ///     if path.contains(' ') {
+    ///         Err("Spaces are not supported!".to_string())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// ```
+    pub fn add_custom<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validators.push(Box::new(validator));
+
+        self
+    }
+
     /// Sets the regex for the requirements
     ///
     /// # Examples
@@ -163,24 +294,292 @@ impl Requirements {
 
     /// Allows creating the path if it doesn't exist yet
     ///
+    /// Paths that don't exist are accepted if their parent directory exists,
+    /// the file/directory and readable/executable restrictions only apply to existing paths
+    ///
     /// # Examples
     ///
     /// ```
     /// use zenity::menu::input::Requirements;
     ///
     /// // Create requirements for validating paths
-    /// let mut path_requirements = Requirements::path();
-    ///
-    /// // Allow creating the path if it doesn't exist yet
-    /// path_requirements.allow_creation();
+    /// let path_requirements = Requirements::path()
+    ///     // Allow creating the path if it doesn't exist yet
+    ///     .allow_creation();
     /// ```
-    pub fn allow_creation(mut self) {
+    pub fn allow_creation(mut self) -> Self {
+        self.path = true;
         self.allow_creating = true;
+
+        self
+    }
+
+    /// Requires the path to be a file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::menu::input::Requirements;
+    ///
+    /// let config = Requirements::path().must_be_file().set_extensions(&["toml"]);
+    /// ```
+    pub fn must_be_file(mut self) -> Self {
+        self.path = true;
+        self.kind = Some(PathKind::File);
+
+        self
+    }
+
+    /// Requires the path to be a directory
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::menu::input::Requirements;
+    ///
+    /// let output = Requirements::path().must_be_dir().must_be_writable();
+    /// ```
+    pub fn must_be_dir(mut self) -> Self {
+        self.path = true;
+        self.kind = Some(PathKind::Directory);
+
+        self
+    }
+
+    /// Requires the path to be readable by the current user
+    pub fn must_be_readable(mut self) -> Self {
+        self.path = true;
+        self.readable = true;
+
+        self
+    }
+
+    /// Requires the path to be writable by the current user
+    ///
+    /// If the path doesn't exist yet (see `allow_creation`) its parent directory has to be writable
+    pub fn must_be_writable(mut self) -> Self {
+        self.path = true;
+        self.writable = true;
+
+        self
+    }
+
+    /// Requires the path to be an executable file
+    ///
+    /// On unix any of the execute permission bits has to be set,
+    /// on other platforms the path needs an executable extension (`exe`, `bat`, `cmd` or `com`)
+    pub fn must_be_executable(mut self) -> Self {
+        self.path = true;
+        self.executable = true;
+
+        self
+    }
+
+    /// Restricts the path to the given extensions (without the leading `.`), ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::menu::input::Requirements;
+    ///
+    /// let image = Requirements::path().set_extensions(&["png", "jpg", "jpeg"]);
+    /// ```
+    pub fn set_extensions(mut self, extensions: &[&str]) -> Self {
+        self.path = true;
+        self.extensions = extensions
+            .iter()
+            .map(|extension| extension.trim_start_matches('.').to_string())
+            .collect();
+
+        self
+    }
+
+    /// Resolves relative paths against `base_dir` instead of the working directory
+    ///
+    /// Only the validation uses the resolved path, the input is returned as typed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::menu::input::Requirements;
+    ///
+    /// // e.g. "src/main.rs" is checked as "/path/to/project/src/main.rs"
+    /// let source = Requirements::path()
+    ///     .must_be_file()
+    ///     .set_base_dir("/path/to/project");
+    /// ```
+    pub fn set_base_dir(mut self, base_dir: impl AsRef<Path>) -> Self {
+        self.path = true;
+        self.base_dir = Some(base_dir.as_ref().to_path_buf());
+
+        self
+    }
+
+    /// Checks the input against the path conditions
+    ///
+    /// # Returns
+    ///
+    /// The note explaining the first failed condition
+    fn check_path(&self, input: &str) -> Result<(), String> {
+        if input.is_empty() {
+            return Err("Please enter a valid path!".to_string());
+        }
+
+        let path = match &self.base_dir {
+            Some(base_dir) => base_dir.join(input),
+            None => PathBuf::from(input),
+        };
+
+        if !self.extensions.is_empty()
+            && !path
+                .extension()
+                .and_then(OsStr::to_str)
+                .is_some_and(|extension| {
+                    self.extensions
+                        .iter()
+                        .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+                })
+        {
+            return Err(format!(
+                "Please enter a path ending with {}!",
+                extension_list(&self.extensions)
+            ));
+        }
+
+        let Ok(metadata) = fs::metadata(&path) else {
+            if !self.allow_creating {
+                return Err("Please enter a valid path!".to_string());
+            }
+
+            // "file.txt" has the empty path as parent, which is the working (or base) directory
+            let parent = match path.parent() {
+                Some(parent) if parent.as_os_str().is_empty() => {
+                    self.base_dir.as_deref().unwrap_or(Path::new("."))
+                }
+                Some(parent) => parent,
+                None => return Err("Please enter a valid path!".to_string()),
+            };
+
+            return match fs::metadata(parent) {
+                Ok(metadata) if metadata.is_dir() => {
+                    if self.writable && !is_writable(parent, &metadata) {
+                        Err("The parent directory isn't writable!".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }
+                _ => Err("The parent directory doesn't exist!".to_string()),
+            };
+        };
+
+        match self.kind {
+            Some(PathKind::File) if !metadata.is_file() => {
+                return Err("Please enter a path to a file!".to_string());
+            }
+            Some(PathKind::Directory) if !metadata.is_dir() => {
+                return Err("Please enter a path to a directory!".to_string());
+            }
+            _ => {}
+        }
+
+        if self.readable && !is_readable(&path, &metadata) {
+            Err("The path isn't readable!".to_string())
+        } else if self.writable && !is_writable(&path, &metadata) {
+            Err("The path isn't writable!".to_string())
+        } else if self.executable && !is_executable(&path, &metadata) {
+            Err("The path isn't executable!".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Runs the custom validators in order
+    ///
+    /// # Returns
+    ///
+    /// The error of the first failing validator
+    fn custom_error(&self, input: &str) -> Option<String> {
+        self.validators
+            .iter()
+            .find_map(|validator| validator(input).err())
+    }
+}
+
+/// formats extensions like `.png, .jpg or .jpeg`
+fn extension_list(extensions: &[String]) -> String {
+    let extensions = extensions
+        .iter()
+        .map(|extension| format!(".{}", extension))
+        .collect::<Vec<_>>();
+
+    match extensions.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => extensions.concat(),
     }
 }
 
+#[inline]
+fn is_readable(path: &Path, metadata: &Metadata) -> bool {
+    if metadata.is_dir() {
+        fs::read_dir(path).is_ok()
+    } else {
+        File::open(path).is_ok()
+    }
+}
+
+#[cfg(unix)]
+#[inline]
+fn is_writable(path: &Path, _metadata: &Metadata) -> bool {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    // the permission bits don't tell if they apply to the current user,
+    // access checks them for the user without touching the file system
+    CString::new(path.as_os_str().as_bytes()).is_ok_and(|path| {
+        // SAFETY: the pointer is a valid nul terminated string for the duration of the call
+        unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 }
+    })
+}
+
+#[cfg(not(unix))]
+#[inline]
+fn is_writable(_path: &Path, metadata: &Metadata) -> bool {
+    !metadata.permissions().readonly()
+}
+
+#[cfg(unix)]
+#[inline]
+fn is_executable(_path: &Path, metadata: &Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    // directories have execute bits too, they mean the directory can be entered
+    metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+#[inline]
+fn is_executable(path: &Path, metadata: &Metadata) -> bool {
+    metadata.is_file()
+        && path
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(|extension| {
+                ["exe", "bat", "cmd", "com"]
+                    .iter()
+                    .any(|executable| executable.eq_ignore_ascii_case(extension))
+            })
+}
+
+/// checks if a parsed value lies in the range set with `Input::set_range`
+type RangeCheck<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// index of the line of an [`Input`] prompt showing the input
+const INPUT_LINE: usize = 1;
+
 /// Represents an input field with validation requirements and optional default value.
-pub struct Input {
+///
+/// The input is parsed into `T` using [`FromStr`], see [`Input::typed`].
+pub struct Input<T = String> {
     /// The title or prompt displayed for the input field.
     title: String,
     /// The validation requirements for the input field.
@@ -189,6 +588,10 @@ pub struct Input {
     default: Option<String>,
     /// Indicates whether the input can be forced without meeting validation requirements.
     allow_force: bool,
+    /// The allowed range of the parsed value and the note shown for values outside of it.
+    range: Option<(RangeCheck<T>, String)>,
+    /// The source of completions, paths are completed for path requirements if None.
+    completer: Option<Box<dyn Completer + Send + Sync>>,
 }
 
 impl Input {
@@ -219,17 +622,52 @@ impl Input {
             requirements: reqs,
             default: None,
             allow_force: false,
+            range: None,
+            completer: None,
+        }
+    }
+}
+
+impl<T: FromStr> Input<T>
+where
+    T::Err: Display,
+{
+    /// Creates a new input field whose input is parsed into `T`.
+    ///
+    /// Input that can't be parsed is rejected and the parse error is shown as a note.
+    /// Requirements can be added with the ``add_requirement`` method,
+    /// they are checked against the input text before it's parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::net::IpAddr;
+    /// use zenity::menu::input::Input;
+    ///
+    /// let address = Input::<IpAddr>::typed("Address");
+    /// ```
+    pub fn typed(title: &str) -> Self {
+        Input {
+            title: title.to_string(),
+            requirements: Vec::new(),
+            default: None,
+            allow_force: false,
+            range: None,
+            completer: None,
         }
     }
 
     /// Starts the input process, displaying the prompt and handling user input.
     ///
     /// This method prompts the user for input, validates it according to the specified requirements,
-    /// and returns the validated input as a boxed string.
+    /// and returns the validated input parsed into `T` (a `String` for inputs created with `new`).
+    ///
+    /// Forcing the input (see ``allow_force``) skips the requirements and the range,
+    /// but the input still has to be parsable.
     ///
     /// # Returns
     ///
-    /// A boxed string containing the validated input.
+    /// The boxed value of the validated input.
     ///
     /// # Example
     ///
@@ -238,76 +676,190 @@ impl Input {
     ///
     /// // init and start directly
     /// let input = Input::new("Name", Requirements::default()).start();
+    ///
+    /// // parse the input
+    /// let port = *Input::<u16>::typed("Port").set_range(1024..).start();
     /// ```
-    pub fn start(&self) -> Box<String> {
+    pub fn start(&self) -> Box<T> {
         let mut force: bool = false;
-        let mut buffer = String::new();
+        let mut buffer = LineBuffer::new();
+        let mut completion = Completion::default();
+
+        // without a completer, the first path requirement enables completing paths
+        let path_completer =
+            self.requirements
+                .iter()
+                .find(|req| req.path)
+                .map(|req| match &req.base_dir {
+                    Some(base_dir) => PathCompleter::new().set_base_dir(base_dir),
+                    None => PathCompleter::new(),
+                });
+        let completer: Option<&dyn Completer> = match &self.completer {
+            Some(completer) => Some(completer.as_ref()),
+            None => path_completer
+                .as_ref()
+                .map(|completer| completer as &dyn Completer),
+        };
 
         // Initialize vectors to store validation status and notes
         let mut validation_status = Vec::new();
         let mut notes = Vec::new();
 
-        loop {
-            raw_mode_wrapper!(self.render_input_prompt(
+        // raw mode is held for the whole prompt, so keys arrive one by one
+        let mut region = begin_region();
+        let mut used_default = false;
+
+        let value = loop {
+            region.update_size();
+            region.render(self.lines(
                 &buffer,
                 validation_status.iter().all(|&status| status),
                 &notes,
+                &completion,
             ));
+            // the region hides the cursor, place it in the input line
+            region.show_cursor(INPUT_LINE, buffer.cursor_width() as u16);
 
-            let result = handle_key_input(&mut buffer, &mut force);
+            let event = read_event();
+            let key = match &event {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => Some(*key_event),
+                _ => None,
+            };
+
+            let result = match (key, completer) {
+                (Some(key_event), Some(completer))
+                    if key_event.code == KeyCode::Tab
+                        // Right at the end of the line accepts the ghost text
+                        || (key_event.code == KeyCode::Right
+                            && key_event.modifiers.is_empty()
+                            && !completion.ghost(&buffer).is_empty()) =>
+                {
+                    completion.tab(&mut buffer, completer);
+                    false
+                }
+                // Up/Down only select candidates while the dropdown is shown
+                (Some(key_event), Some(_))
+                    if matches!(key_event.code, KeyCode::Up | KeyCode::Down)
+                        && completion.has_dropdown() =>
+                {
+                    completion.cycle(&mut buffer, key_event.code == KeyCode::Down);
+                    false
+                }
+                (key, completer) => {
+                    let result = handle_key_event(&mut buffer, event, &mut force);
+
+                    if let (Some(_), Some(completer)) = (key, completer) {
+                        completion.update(&buffer, completer);
+                    }
+
+                    result
+                }
+            };
 
             // Perform validation for each requirement and store results
             validation_status.clear();
             notes.clear();
 
             for req in &self.requirements {
-                let mut path_valid = true;
-
-                if req.path {
-                    path_valid = Self::validate_path(&buffer);
-                }
+                let path_error = if req.path {
+                    req.check_path(&buffer).err()
+                } else {
+                    None
+                };
+                let path_valid = path_error.is_none();
 
                 let regex_valid = req
                     .regex
                     .as_ref()
-                    .map_or(true, |regex| Self::validate_regex(&buffer, regex));
+                    .is_none_or(|regex| regex.is_match(&buffer));
+
+                // custom validators only run for input passing the other conditions
+                let custom_error = if path_valid && regex_valid {
+                    req.custom_error(&buffer)
+                } else {
+                    None
+                };
 
                 // Push the validation status of each requirement
-                validation_status.push(path_valid && regex_valid);
+                validation_status.push(path_valid && regex_valid && custom_error.is_none());
 
-                // Store notes for each requirement
-                notes.push(if validation_status.last() == Some(&true) {
-                    req.true_note.clone()
-                } else {
-                    req.false_note.clone()
+                // Store notes for each requirement, errors of validators replace the invalid note
+                notes.push(match custom_error {
+                    Some(error) => Some(Self::note(&error)),
+                    None if validation_status.last() == Some(&true) => {
+                        req.true_note.as_deref().map(Self::note)
+                    }
+                    None => req
+                        .false_note
+                        .as_deref()
+                        .or(path_error.as_deref())
+                        .map(Self::note),
                 });
             }
 
+            let parsed = match buffer.parse::<T>() {
+                Ok(parsed) => {
+                    let in_range = self.range.as_ref().is_none_or(|(check, _)| check(&parsed));
+
+                    validation_status.push(in_range);
+                    notes.push(
+                        self.range
+                            .as_ref()
+                            .filter(|_| !in_range)
+                            .map(|(_, note)| Self::error_note(note)),
+                    );
+
+                    Some(parsed)
+                }
+                Err(err) => {
+                    validation_status.push(false);
+                    // nothing typed yet isn't an error worth pointing out
+                    notes.push((!buffer.is_empty()).then(|| Self::error_note(&err.to_string())));
+
+                    None
+                }
+            };
+
             if result {
                 // Check if all requirements are satisfied
                 if validation_status.iter().all(|&status| status) {
-                    break;
-                } else if self.default.is_some() && buffer.is_empty() {
-                    buffer = self.default.clone().unwrap();
-                    break;
+                    if let Some(parsed) = parsed {
+                        break parsed;
+                    }
+                } else if buffer.is_empty() {
+                    if let Some(default) = self.default.as_deref() {
+                        match self.parse_default(default) {
+                            Ok(default) => {
+                                used_default = true;
+                                break default;
+                            }
+                            Err(error) => notes.push(Some(Self::error_note(&format!(
+                                "Invalid default: {}",
+                                error
+                            )))),
+                        }
+                    }
                 }
             }
 
             if force && self.allow_force {
-                break;
+                if let Some(parsed) = parsed {
+                    break parsed;
+                }
             }
-        }
+        };
 
-        // clear the line before exit
-        execute!(
-            io::stdout(),
-            cursor::MoveTo(0, 4),
-            Clear(ClearType::FromCursorDown),
-            cursor::Show,
-        )
-        .unwrap();
+        // replace the prompt with the accepted text
+        let text = match &self.default {
+            Some(default) if used_default => default.clone(),
+            _ => buffer.to_string(),
+        };
+        end_region(&[
+            StyledString::new(&format!("{} ", self.title)),
+            StyledString::simple(&text, Some(Color::Green), None, None),
+        ]);
 
-        Box::new(buffer)
+        Box::new(value)
     }
 
     /// Adds a new requirement to the input.
@@ -332,7 +884,7 @@ impl Input {
     ///
     /// **Note: **
     /// - This feature may not work in all terminal environments.
-    /// Refer to issue [#685](https://github.com/crossterm-rs/crossterm/issues/685) for more information.
+    ///   Refer to issue [#685](https://github.com/crossterm-rs/crossterm/issues/685) for more information.
     ///
     /// # Example
     ///
@@ -348,9 +900,33 @@ impl Input {
         self
     }
 
+    /// Sets the source of completions for the input, see [`Completer`].
+    ///
+    /// While typing, the completion is shown as grey ghost text and accepted with Tab
+    /// (or Right at the end of the line), multiple candidates are listed below the input
+    /// and selected with Up/Down or by pressing Tab again.
+    ///
+    /// Inputs with a path requirement complete paths if no completer is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::{Input, Requirements};
+    ///
+    /// let host = Input::new("Host", Requirements::custom(|_| Ok(())))
+    ///     .set_completer(vec!["alpha.example.com", "beta.example.com"]);
+    /// ```
+    pub fn set_completer(mut self, completer: impl Completer + Send + Sync + 'static) -> Self {
+        self.completer = Some(Box::new(completer));
+
+        self
+    }
+
     /// Sets the default value, which can be accepted by pressing Enter.
     ///
     /// Pressing Enter without typing anything will accept the default value.
+    /// The default is parsed and checked against the range (see `set_range`) when it's accepted,
+    /// if that fails an error note is shown instead.
     ///
     /// # Example
     ///
@@ -367,158 +943,154 @@ impl Input {
     }
 
     // helper functions:
+    /// Parses the default value and checks it against the range
+    fn parse_default(&self, default: &str) -> Result<T, String> {
+        let parsed = default.parse::<T>().map_err(|err| err.to_string())?;
+
+        match &self.range {
+            Some((check, note)) if !check(&parsed) => Err(note.clone()),
+            _ => Ok(parsed),
+        }
+    }
+
     #[inline]
-    fn validate_path(path: &str) -> bool {
-        // useless function but might change something here later...
-        Path::new(path).exists()
+    fn note(text: &str) -> StyledString {
+        StyledString::simple(text, Some(Color::DarkGrey), None, None)
     }
 
     #[inline]
-    fn validate_regex(buffer: &str, regex: &Regex) -> bool {
-        if regex.is_match(buffer) {
-            true
-        } else {
-            execute!(
-                io::stdout(),
-                cursor::MoveTo(0, 5),
-                Clear(ClearType::CurrentLine)
-            )
-            .unwrap();
-            false
-        }
+    fn error_note(text: &str) -> StyledString {
+        StyledString::simple(text, Some(Color::DarkRed), None, None)
     }
 
-    fn render_input_prompt(&self, buffer: &str, valid: bool, notes: &[Option<String>]) {
-        // clear the line before rendering
-        execute!(
-            io::stdout(),
-            cursor::MoveTo(0, 4),
-            Clear(ClearType::CurrentLine),
-            cursor::Hide,
-        )
-        .unwrap();
-
-        // determine color based on validity and color enablement
-        let (text_color, content) = if !buffer.is_empty() || self.default.is_none() {
-            let text_color = if *ENABLE_COLOR {
-                if !valid {
-                    Color::DarkRed
-                } else {
-                    Color::Green
-                }
-            } else {
-                Color::Reset
-            };
-            (text_color, buffer.to_string())
-        } else {
-            let text_color = if *ENABLE_COLOR {
-                Color::Grey
-            } else {
-                Color::Reset
-            };
-            (text_color, self.default.clone().unwrap_or_default())
-        };
+    /// The lines of the prompt, the input is the line at `INPUT_LINE`
+    fn lines(
+        &self,
+        buffer: &LineBuffer,
+        valid: bool,
+        notes: &[Option<StyledString>],
+        completion: &Completion,
+    ) -> Vec<Vec<StyledString>> {
+        let mut input = Vec::new();
 
-        // render the prompt
-        execute!(
-            io::stdout(),
-            Print(&self.title),
-            cursor::MoveToNextLine(1),
-            Clear(ClearType::CurrentLine),
-            SetForegroundColor(text_color),
-            Print(content),
-        )
-        .unwrap();
+        match &self.default {
+            Some(default) if buffer.is_empty() => {
+                input.push(StyledString::simple(
+                    &format!("{} (Default)", default),
+                    Some(Color::Grey),
+                    None,
+                    None,
+                ));
+            }
+            _ => {
+                let text_color = if valid { Color::Green } else { Color::DarkRed };
+                input.push(StyledString::simple(buffer, Some(text_color), None, None));
 
-        // if using default, indicate it
-        if self.default.is_some() && buffer.is_empty() {
-            execute!(io::stdout(), Print(" (Default)")).unwrap();
+                // show what Tab would insert behind the input
+                let ghost = completion.ghost(buffer);
+                if !ghost.is_empty() {
+                    input.push(StyledString::simple(
+                        ghost,
+                        Some(Color::DarkGrey),
+                        None,
+                        None,
+                    ));
+                }
+            }
         }
 
-        // reset color
-        execute!(
-            io::stdout(),
-            SetForegroundColor(Color::Reset),
-            cursor::SavePosition,
-            cursor::MoveToNextLine(2)
-        )
-        .unwrap();
-
-        if *ENABLE_COLOR {
-            execute!(io::stdout(), SetForegroundColor(Color::DarkGrey)).unwrap();
-        }
+        let mut lines = vec![vec![StyledString::new(&self.title)], input];
 
-        // Print notes
-        for note in notes.iter() {
-            match note {
-                Some(note_str) => {
-                    execute!(
-                        io::stdout(),
-                        cursor::MoveToNextLine(1),
-                        Print("- "),
-                        Print(note_str)
-                    )
-                    .unwrap();
-                }
-                None => {
-                    execute!(
-                        io::stdout(),
-                        cursor::MoveToNextLine(1),
-                        Clear(ClearType::CurrentLine),
-                        cursor::MoveToPreviousLine(1),
-                    )
-                    .unwrap();
-                }
-            }
+        // list multiple completion candidates below the input, the notes follow a blank line
+        let dropdown = completion.dropdown();
+        if dropdown.is_empty() {
+            lines.push(Vec::new());
         }
+        lines.extend(dropdown);
+        lines.push(Vec::new());
 
-        if self.allow_force && !buffer.is_empty() && !valid {
-            execute!(
-                io::stdout(),
-                cursor::MoveToNextLine(2),
-                Print("Press SHIFT + Enter to force input"),
-                cursor::MoveToPreviousLine(1),
-            )
-            .unwrap();
-        } else {
-            execute!(
-                io::stdout(),
-                cursor::MoveToNextLine(2),
-                Clear(ClearType::CurrentLine),
-                cursor::MoveToPreviousLine(2),
-            )
-            .unwrap();
+        for note in notes.iter().flatten() {
+            lines.push(vec![StyledString {
+                string: format!("- {}", note.string),
+                style: note.style,
+            }]);
         }
 
-        if self.default.is_some() && buffer.is_empty() {
-            execute!(
-                io::stdout(),
-                cursor::MoveToNextLine(2),
-                Print("Press Enter to accept default"),
-                cursor::MoveToPreviousLine(1),
-            )
-            .unwrap();
+        let hint = if self.allow_force && !buffer.is_empty() && !valid {
+            Some("Press SHIFT + Enter to force input")
+        } else if self.default.is_some() && buffer.is_empty() {
+            Some("Press Enter to accept default")
         } else {
-            execute!(
-                io::stdout(),
-                cursor::MoveToNextLine(2),
-                Clear(ClearType::CurrentLine),
-                cursor::MoveToPreviousLine(2),
-            )
-            .unwrap();
+            None
+        };
+
+        if let Some(hint) = hint {
+            lines.push(Vec::new());
+            lines.push(vec![Self::note(hint)]);
         }
 
-        // reset color
-        execute!(
-            io::stdout(),
-            cursor::RestorePosition,
-            SetForegroundColor(Color::Reset),
-            cursor::Show,
-        )
-        .unwrap();
+        lines
+    }
+}
+
+impl<T> Input<T>
+where
+    T: FromStr + PartialOrd + Display + Clone + Send + Sync + 'static,
+    T::Err: Display,
+{
+    /// Restricts the parsed value to a range, e.g. `1..=65535` for a port.
+    ///
+    /// Values outside of the range are rejected with a note naming the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::Input;
+    ///
+    /// let port = Input::<u16>::typed("Port").set_range(1024..);
+    /// let ratio = Input::<f64>::typed("Ratio").set_range(0.0..=1.0);
+    /// ```
+    pub fn set_range(mut self, range: impl RangeBounds<T>) -> Self {
+        let start = range.start_bound().cloned();
+        let end = range.end_bound().cloned();
+        let note = range_note(start.as_ref(), end.as_ref());
+
+        self.range = Some((
+            Box::new(move |value: &T| (start.as_ref(), end.as_ref()).contains(value)),
+            note,
+        ));
+
+        self
     }
 }
 
+/// describes the range for the note shown for values outside of it
+fn range_note<T: Display>(start: Bound<&T>, end: Bound<&T>) -> String {
+    if let (Bound::Included(start), Bound::Included(end)) = (start, end) {
+        return format!("Must be between {} and {}", start, end);
+    }
+
+    let lower = match start {
+        Bound::Included(start) => Some(format!("at least {}", start)),
+        Bound::Excluded(start) => Some(format!("greater than {}", start)),
+        Bound::Unbounded => None,
+    };
+    let upper = match end {
+        Bound::Included(end) => Some(format!("at most {}", end)),
+        Bound::Excluded(end) => Some(format!("less than {}", end)),
+        Bound::Unbounded => None,
+    };
+
+    format!(
+        "Must be {}",
+        lower
+            .into_iter()
+            .chain(upper)
+            .collect::<Vec<_>>()
+            .join(" and ")
+    )
+}
+
 /// Represents a confirmation prompt with a title and a default value.
 ///
 /// This struct provides a confirmation prompt to the user, allowing them to press 'y' or 'n' for yes or no.
@@ -609,7 +1181,10 @@ impl Confirm {
         execute!(io::stdout(), Print(default_text), cursor::Hide).unwrap();
 
         loop {
-            if let Event::Key(key_event) = crossterm::event::read().unwrap() {
+            let event = crossterm::event::read().unwrap();
+            guard::handle_interrupt_event(&event);
+
+            if let Event::Key(key_event) = event {
                 let KeyEvent { code, .. } = key_event;
 
                 let result = match code {
@@ -634,3 +1209,438 @@ impl Confirm {
         }
     }
 }
+
+/// Represents a prompt for secrets like passwords or API tokens.
+///
+/// The typed characters are masked (or not shown at all) and the typed text
+/// is zeroed in memory once it isn't needed anymore.
+///
+/// # Examples
+///
+/// ```no_run
+/// use zenity::menu::input::Password;
+///
+/// let password = Password::new("Password:")
+///     .with_confirmation("Confirm password:")
+///     .start();
+///
+/// // the password is zeroed when `password` is dropped
+/// assert!(!password.is_empty());
+/// ```
+pub struct Password {
+    /// The title or prompt displayed for the input field.
+    title: String,
+    /// The character shown for every typed character, None to show nothing.
+    mask: Option<char>,
+    /// The title of the second prompt asking to repeat the input.
+    confirmation: Option<String>,
+    /// The note shown if the repeated input doesn't match.
+    mismatch_note: String,
+}
+
+/// index of the line of a [`Password`] prompt showing the masked input
+const MASKED_LINE: usize = 1;
+
+impl Password {
+    /// Creates a new password prompt masking the input with `*`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::Password;
+    ///
+    /// let password = Password::new("API token:");
+    /// ```
+    pub fn new(title: &str) -> Self {
+        Password {
+            title: title.to_string(),
+            mask: Some('*'),
+            confirmation: None,
+            mismatch_note: "The inputs do not match, please try again".to_string(),
+        }
+    }
+
+    /// Sets the character shown for every typed character.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::Password;
+    ///
+    /// let password = Password::new("Password:").set_mask('•');
+    /// ```
+    pub fn set_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+
+        self
+    }
+
+    /// Hides the input completely, not even its length is shown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::Password;
+    ///
+    /// let password = Password::new("Password:").hide_input();
+    /// ```
+    pub fn hide_input(mut self) -> Self {
+        self.mask = None;
+
+        self
+    }
+
+    /// Asks to repeat the input in a second prompt with the given title.
+    ///
+    /// If the inputs don't match both prompts start over and the mismatch note is shown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::Password;
+    ///
+    /// let password = Password::new("New password:").with_confirmation("Repeat password:");
+    /// ```
+    pub fn with_confirmation(mut self, title: &str) -> Self {
+        self.confirmation = Some(title.to_string());
+
+        self
+    }
+
+    /// Sets the note shown if the repeated input doesn't match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::input::Password;
+    ///
+    /// let password = Password::new("New password:")
+    ///     .with_confirmation("Repeat password:")
+    ///     .set_mismatch_note("Passwords do not match");
+    /// ```
+    pub fn set_mismatch_note(mut self, note: &str) -> Self {
+        self.mismatch_note = note.to_string();
+
+        self
+    }
+
+    /// Starts the prompt and blocks until the input is confirmed with Enter.
+    ///
+    /// The line editing keys of [`Input`] are supported,
+    /// with a mask the cursor is shown at its position in the masked input.
+    /// Hidden input (see `hide_input`) shows no cursor, so only typing and Backspace are visible.
+    ///
+    /// # Returns
+    ///
+    /// The input, zeroed in memory when dropped.
+    pub fn start(&self) -> Zeroizing<String> {
+        let mut region = begin_region();
+        let mut mismatch = false;
+
+        let mut password = loop {
+            let password = self.read(&mut region, &self.title, mismatch);
+
+            let Some(title) = &self.confirmation else {
+                break password;
+            };
+
+            if **password == **self.read(&mut region, title, false) {
+                break password;
+            }
+
+            mismatch = true;
+        };
+
+        end_region(&[StyledString::new(&self.title)]);
+
+        Zeroizing::new(mem::take(&mut *password).into_string())
+    }
+
+    fn read(&self, region: &mut LiveRegion, title: &str, mismatch: bool) -> Zeroizing<LineBuffer> {
+        let mut buffer = Zeroizing::new(LineBuffer::new());
+
+        loop {
+            region.update_size();
+            region.render(self.lines(title, &buffer, mismatch));
+
+            // the region hides the cursor, place it in the masked line
+            if let Some(column) = self.cursor_column(&buffer) {
+                region.show_cursor(MASKED_LINE, column);
+            }
+
+            if handle_key_input(&mut buffer, &mut false) {
+                return buffer;
+            }
+        }
+    }
+
+    /// The column of the cursor in the masked line, None if the input is hidden
+    fn cursor_column(&self, buffer: &LineBuffer) -> Option<u16> {
+        let mask_width = self.mask?.to_string().width();
+        let graphemes = buffer.before_cursor().graphemes(true).count();
+
+        Some((graphemes * mask_width).min(u16::MAX as usize) as u16)
+    }
+
+    fn lines(&self, title: &str, buffer: &LineBuffer, mismatch: bool) -> Vec<Vec<StyledString>> {
+        let masked = self
+            .mask
+            .map(|mask| mask.to_string().repeat(buffer.graphemes(true).count()))
+            .unwrap_or_default();
+
+        // the masked input is the line at `MASKED_LINE`
+        let mut lines = vec![
+            vec![StyledString::new(title)],
+            vec![StyledString::new(&masked)],
+        ];
+
+        if mismatch {
+            lines.push(note(&self.mismatch_note));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu::temp_dir::TempDir;
+
+    fn strings(lines: &[Vec<StyledString>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|part| part.string.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_password_lines() {
+        let mut buffer = LineBuffer::new();
+        buffer.set("sécret");
+
+        let password = Password::new("Password:");
+        assert_eq!(
+            strings(&password.lines("Password:", &buffer, false)),
+            vec!["Password:", "******"]
+        );
+
+        let password = password.hide_input().set_mismatch_note("no match");
+        assert_eq!(
+            strings(&password.lines("Password:", &buffer, true)),
+            vec!["Password:", "", "- no match"]
+        );
+    }
+
+    #[test]
+    fn test_input_lines() {
+        let input = Input::new("Branch:", Requirements::default())
+            .allow_force()
+            .set_default("main");
+        let mut completion = Completion::default();
+        let completer = vec!["feature/input", "feature/select"];
+
+        let mut buffer = LineBuffer::new();
+        assert_eq!(
+            strings(&input.lines(&buffer, true, &[], &completion)),
+            vec![
+                "Branch:",
+                "main (Default)",
+                "",
+                "",
+                "",
+                "Press Enter to accept default"
+            ]
+        );
+
+        buffer.set("fe");
+        completion.update(&buffer, &completer);
+        let notes = [None, Some(Input::<String>::error_note("invalid"))];
+        let lines = strings(&input.lines(&buffer, false, &notes, &completion));
+        assert_eq!(lines[1], "feature/");
+        assert_eq!(lines[2..4].len(), 2);
+        assert_eq!(
+            lines[4..],
+            ["", "- invalid", "", "Press SHIFT + Enter to force input"]
+        );
+    }
+
+    #[test]
+    fn test_password_cursor_column() {
+        let mut buffer = LineBuffer::new();
+        buffer.set("sécret");
+        buffer.move_word_left();
+        buffer.move_right();
+
+        assert_eq!(Password::new("Password:").cursor_column(&buffer), Some(1));
+        assert_eq!(
+            Password::new("Password:")
+                .set_mask('●')
+                .cursor_column(&buffer),
+            Some(1)
+        );
+        assert_eq!(
+            Password::new("Password:")
+                .set_mask('＊')
+                .cursor_column(&buffer),
+            Some(2)
+        );
+        assert_eq!(
+            Password::new("Password:")
+                .hide_input()
+                .cursor_column(&buffer),
+            None
+        );
+    }
+
+    #[test]
+    fn test_range_note() {
+        assert_eq!(
+            range_note(Bound::Included(&1), Bound::Included(&10)),
+            "Must be between 1 and 10"
+        );
+        assert_eq!(
+            range_note(Bound::Included(&1024), Bound::Unbounded),
+            "Must be at least 1024"
+        );
+        assert_eq!(
+            range_note(Bound::Excluded(&0.0), Bound::Excluded(&1.5)),
+            "Must be greater than 0 and less than 1.5"
+        );
+    }
+
+    #[test]
+    fn test_set_range() {
+        let input = Input::<i32>::typed("Number").set_range(-5..5);
+        let (check, note) = input.range.as_ref().unwrap();
+
+        assert!(check(&-5));
+        assert!(!check(&5));
+        assert_eq!(note, "Must be at least -5 and less than 5");
+    }
+
+    #[test]
+    fn test_parse_default() {
+        let input = Input::<u16>::typed("Port").set_range(1024..);
+
+        assert_eq!(input.parse_default("8080"), Ok(8080));
+        assert_eq!(
+            input.parse_default("80"),
+            Err("Must be at least 1024".to_string())
+        );
+        assert_eq!(
+            input.parse_default("http"),
+            Err("invalid digit found in string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_custom_requirements() {
+        let requirements = Requirements::custom(|input| match input.parse::<u16>() {
+            Ok(port) if port < 1024 => Err("Port is reserved".to_string()),
+            Ok(_) => Ok(()),
+            Err(_) => Err("Not a port".to_string()),
+        })
+        .add_custom(|input| {
+            if input == "8080" {
+                Err("Port is in use".to_string())
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(requirements.custom_error("9000"), None);
+        assert_eq!(
+            requirements.custom_error("80").as_deref(),
+            Some("Port is reserved")
+        );
+        assert_eq!(
+            requirements.custom_error("8080").as_deref(),
+            Some("Port is in use")
+        );
+    }
+
+    #[test]
+    fn test_path_kind() {
+        let dir = TempDir::new("path-kind");
+        fs::write(dir.join("file.txt"), "").unwrap();
+
+        let file = Requirements::path().must_be_file().set_base_dir(&dir);
+        assert_eq!(file.check_path("file.txt"), Ok(()));
+        assert_eq!(
+            file.check_path("."),
+            Err("Please enter a path to a file!".to_string())
+        );
+        assert_eq!(
+            file.check_path("missing.txt"),
+            Err("Please enter a valid path!".to_string())
+        );
+
+        let directory = Requirements::path().must_be_dir();
+        assert_eq!(directory.check_path(dir.to_str().unwrap()), Ok(()));
+        assert_eq!(
+            directory.check_path(dir.join("file.txt").to_str().unwrap()),
+            Err("Please enter a path to a directory!".to_string())
+        );
+    }
+
+    #[test]
+    fn test_path_creation() {
+        let dir = TempDir::new("path-creation");
+
+        let requirements = Requirements::path()
+            .allow_creation()
+            .set_base_dir(&dir)
+            .set_extensions(&["toml", ".json"]);
+
+        assert_eq!(requirements.check_path("new.TOML"), Ok(()));
+        assert_eq!(
+            requirements.check_path("missing/new.toml"),
+            Err("The parent directory doesn't exist!".to_string())
+        );
+        assert_eq!(
+            requirements.check_path("new.yaml"),
+            Err("Please enter a path ending with .toml or .json!".to_string())
+        );
+
+        // checking the parent directory doesn't leave files behind
+        let writable = Requirements::path()
+            .allow_creation()
+            .must_be_writable()
+            .set_base_dir(&dir);
+        assert_eq!(writable.check_path("new.toml"), Ok(()));
+        assert_eq!(fs::read_dir(&*dir).unwrap().count(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_path_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new("path-executable");
+        let script = dir.join("script.sh");
+        fs::write(&script, "").unwrap();
+
+        let requirements = Requirements::path().must_be_readable().must_be_executable();
+        assert_eq!(
+            requirements.check_path(script.to_str().unwrap()),
+            Err("The path isn't executable!".to_string())
+        );
+
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        assert_eq!(requirements.check_path(script.to_str().unwrap()), Ok(()));
+        assert_eq!(
+            requirements.check_path(dir.to_str().unwrap()),
+            Err("The path isn't executable!".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extension_list() {
+        let extensions =
+            |list: &[&str]| extension_list(&list.iter().map(|e| e.to_string()).collect::<Vec<_>>());
+
+        assert_eq!(extensions(&["png"]), ".png");
+        assert_eq!(extensions(&["png", "jpg", "jpeg"]), ".png, .jpg or .jpeg");
+    }
+}
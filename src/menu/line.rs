@@ -0,0 +1,243 @@
+//! editable line of text with a cursor, used by the input prompts
+
+use std::ops::Deref;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use zeroize::Zeroize;
+
+/// text being typed into a prompt and the position of the cursor in it
+///
+/// the cursor is a byte index that always lies on a grapheme boundary,
+/// so wide and combined characters are moved over and deleted as a whole
+///
+/// words (for Ctrl+W and Alt+B/F) are runs of non-whitespace characters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct LineBuffer {
+    text: String,
+    cursor: usize,
+}
+
+impl LineBuffer {
+    /// creates an empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// replaces the text, placing the cursor at its end
+    #[cfg(test)]
+    pub fn set(&mut self, text: &str) {
+        self.text = text.to_string();
+        self.cursor = self.text.len();
+    }
+
+    /// the display width of the text before the cursor,
+    /// i.e. the column of the cursor relative to the start of the text
+    pub fn cursor_width(&self) -> usize {
+        self.text[..self.cursor].width()
+    }
+
+    /// the text before the cursor
+    pub fn before_cursor(&self) -> &str {
+        &self.text[..self.cursor]
+    }
+
+    /// replaces the text before the cursor, keeping the cursor in front of the text after it
+    pub fn replace_before_cursor(&mut self, text: &str) {
+        self.text.replace_range(..self.cursor, text);
+        self.cursor = text.len();
+    }
+
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    /// inserts a char at the cursor and moves the cursor behind it
+    pub fn insert(&mut self, c: char) {
+        if self.text.len() + c.len_utf8() > self.text.capacity() {
+            // grow by hand so the old allocation is zeroed instead of just freed,
+            // otherwise copies of a typed secret could be left behind in memory
+            let mut grown = String::with_capacity((self.text.capacity() * 2).max(32));
+            grown.push_str(&self.text);
+            self.text.zeroize();
+            self.text = grown;
+        }
+
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    /// deletes the grapheme before the cursor
+    pub fn backspace(&mut self) {
+        let start = self.previous_boundary();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// deletes the grapheme after the cursor
+    pub fn delete(&mut self) {
+        let end = self.next_boundary();
+        self.text.replace_range(self.cursor..end, "");
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.previous_boundary();
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = self.next_boundary();
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.text.len();
+    }
+
+    /// moves the cursor to the start of the current or previous word
+    pub fn move_word_left(&mut self) {
+        self.cursor = self.previous_word_start();
+    }
+
+    /// moves the cursor to the end of the current or next word
+    pub fn move_word_right(&mut self) {
+        let rest = &self.text[self.cursor..];
+        let word = rest.len() - rest.trim_start().len();
+        let end = rest[word..]
+            .find(char::is_whitespace)
+            .map_or(rest.len(), |end| word + end);
+
+        self.cursor += end;
+    }
+
+    /// deletes from the start of the current or previous word to the cursor
+    pub fn delete_word_left(&mut self) {
+        let start = self.previous_word_start();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// deletes everything before the cursor
+    pub fn delete_to_start(&mut self) {
+        self.text.replace_range(..self.cursor, "");
+        self.cursor = 0;
+    }
+
+    /// deletes everything after the cursor
+    pub fn delete_to_end(&mut self) {
+        self.text.truncate(self.cursor);
+    }
+
+    fn previous_boundary(&self) -> usize {
+        self.text[..self.cursor]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    fn next_boundary(&self) -> usize {
+        self.text[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map_or(self.cursor, |grapheme| self.cursor + grapheme.len())
+    }
+
+    fn previous_word_start(&self) -> usize {
+        let before = self.text[..self.cursor].trim_end();
+
+        before.rfind(char::is_whitespace).map_or(0, |index| {
+            index + before[index..].chars().next().unwrap().len_utf8()
+        })
+    }
+}
+
+impl Deref for LineBuffer {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Zeroize for LineBuffer {
+    fn zeroize(&mut self) {
+        self.text.zeroize();
+        self.cursor = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(text: &str) -> LineBuffer {
+        let mut buffer = LineBuffer::new();
+        buffer.set(text);
+        buffer
+    }
+
+    #[test]
+    fn test_insert_at_cursor() {
+        let mut line = buffer("ac");
+
+        line.move_left();
+        line.insert('b');
+
+        assert_eq!(&*line, "abc");
+        assert_eq!(line.cursor_width(), 2);
+    }
+
+    #[test]
+    fn test_graphemes() {
+        let mut line = buffer("a日e\u{301}");
+
+        line.backspace();
+        assert_eq!(&*line, "a日");
+
+        line.move_left();
+        assert_eq!(line.cursor_width(), 1);
+        line.move_right();
+        assert_eq!(line.cursor_width(), 3);
+
+        line.move_home();
+        line.delete();
+        assert_eq!(&*line, "日");
+    }
+
+    #[test]
+    fn test_words() {
+        let mut line = buffer("git commit  -m");
+
+        line.move_word_left();
+        assert_eq!(line.cursor_width(), 12);
+        line.move_word_left();
+        assert_eq!(line.cursor_width(), 4);
+        line.move_word_right();
+        assert_eq!(line.cursor_width(), 10);
+
+        line.delete_word_left();
+        assert_eq!(&*line, "git   -m");
+        line.delete_word_left();
+        assert_eq!(&*line, "  -m");
+    }
+
+    #[test]
+    fn test_delete_to_start_and_end() {
+        let mut line = buffer("hello world");
+
+        line.move_word_left();
+        line.delete_to_start();
+        assert_eq!(&*line, "world");
+
+        line.move_right();
+        line.delete_to_end();
+        assert_eq!(&*line, "w");
+    }
+}
@@ -0,0 +1,109 @@
+//! cursor and scroll window shared by the list menus
+
+use std::ops::Range;
+
+use crossterm::event::KeyCode;
+
+/// position of the cursor in a list of `len` items,
+/// of which a window of `page_size` items is visible
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ListState {
+    /// index of the highlighted item
+    pub cursor: usize,
+    /// index of the first visible item
+    offset: usize,
+    page_size: usize,
+    len: usize,
+}
+
+impl ListState {
+    pub fn new(len: usize, page_size: usize, cursor: usize) -> Self {
+        let mut state = ListState {
+            cursor: cursor.min(len.saturating_sub(1)),
+            offset: 0,
+            page_size: page_size.max(1),
+            len,
+        };
+        state.scroll();
+        state
+    }
+
+    /// the indices of the visible items
+    pub fn window(&self) -> Range<usize> {
+        self.offset..(self.offset + self.page_size).min(self.len)
+    }
+
+    /// moves the cursor for the arrow, Home/End and PageUp/PageDown keys
+    ///
+    /// up and down wrap around at the ends of the list
+    ///
+    /// # Returns
+    ///
+    /// false if the key isn't a navigation key
+    pub fn navigate(&mut self, code: KeyCode) -> bool {
+        let last = self.len.saturating_sub(1);
+
+        self.cursor = match code {
+            KeyCode::Up if self.cursor == 0 => last,
+            KeyCode::Up => self.cursor - 1,
+            KeyCode::Down if self.cursor >= last => 0,
+            KeyCode::Down => self.cursor + 1,
+            KeyCode::Home => 0,
+            KeyCode::End => last,
+            KeyCode::PageUp => self.cursor.saturating_sub(self.page_size),
+            KeyCode::PageDown => (self.cursor + self.page_size).min(last),
+            _ => return false,
+        };
+
+        self.scroll();
+        true
+    }
+
+    /// moves the window so the cursor is visible
+    fn scroll(&mut self) {
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        } else if self.cursor >= self.offset + self.page_size {
+            self.offset = self.cursor + 1 - self.page_size;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigate() {
+        let mut state = ListState::new(5, 3, 0);
+
+        assert!(state.navigate(KeyCode::Up));
+        assert_eq!(state.cursor, 4);
+        assert_eq!(state.window(), 2..5);
+
+        state.navigate(KeyCode::Down);
+        assert_eq!(state.cursor, 0);
+        assert_eq!(state.window(), 0..3);
+
+        state.navigate(KeyCode::PageDown);
+        assert_eq!(state.cursor, 3);
+        assert_eq!(state.window(), 1..4);
+
+        state.navigate(KeyCode::PageDown);
+        assert_eq!(state.cursor, 4);
+
+        state.navigate(KeyCode::Home);
+        assert_eq!(state.window(), 0..3);
+
+        assert!(!state.navigate(KeyCode::Enter));
+    }
+
+    #[test]
+    fn test_default_cursor_visible() {
+        let state = ListState::new(10, 4, 8);
+
+        assert_eq!(state.cursor, 8);
+        assert_eq!(state.window(), 5..9);
+        assert_eq!(ListState::new(3, 4, 8).cursor, 2);
+    }
+}
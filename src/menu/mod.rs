@@ -1,16 +1,103 @@
 //! implementation for menus
 //! (work in progress checkout: [issue#20](https://github.com/Arteiii/zenity/issues/20))
+use std::io::{stdout, Write};
+
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crossterm::{cursor, queue, terminal};
+
+use crate::guard;
+use crate::style::{Color, StyledString};
+use crate::terminal::console_guard;
+use crate::terminal::console_render::{queue_styled_line, LiveRegion};
 
+pub mod completion;
 pub mod input;
 pub mod requirements;
+pub mod select;
+
+mod fuzzy;
+mod line;
+mod list;
+#[cfg(test)]
+mod temp_dir;
+
+use line::LineBuffer;
+
+/// reads the next key press, forwarding Ctrl+C to the interrupt guard
+///
+/// returns None for all other events (key releases, resizes, ...)
+pub(crate) fn read_key() -> Option<KeyEvent> {
+    match read_event() {
+        Event::Key(key_event) if key_event.kind == KeyEventKind::Press => Some(key_event),
+        _ => None,
+    }
+}
+
+/// grey note in the style of the input requirements
+pub(crate) fn note(text: &str) -> Vec<StyledString> {
+    vec![StyledString::simple(
+        &format!("- {}", text),
+        Some(Color::DarkGrey),
+        None,
+        None,
+    )]
+}
+
+/// enables raw mode and starts drawing a menu at the cursor position
+pub(crate) fn begin_region() -> LiveRegion {
+    enable_raw_mode().expect("Failed to enable raw-mode");
+    console_guard::set_live_region(true);
+
+    let mut region = LiveRegion::new();
+    region.reanchor();
+    region
+}
+
+/// clears the menu, prints `summary` in its place and disables raw mode
+pub(crate) fn end_region(summary: &[StyledString]) {
+    console_guard::set_live_region(false);
+
+    let mut stdout = stdout().lock();
+    queue!(
+        stdout,
+        cursor::RestorePosition,
+        terminal::Clear(terminal::ClearType::FromCursorDown),
+    )
+    .unwrap();
+    queue_styled_line(&mut stdout, 0, summary).unwrap();
+    queue!(stdout, cursor::MoveToNextLine(1), cursor::Show).unwrap();
+    stdout.flush().unwrap();
+
+    disable_raw_mode().expect("Failed to disable raw-mode");
+}
+
+pub(crate) fn handle_key_input(buffer: &mut LineBuffer, force: &mut bool) -> bool {
+    handle_key_event(buffer, read_event(), force)
+}
+
+/// reads the next event, forwarding Ctrl+C to the interrupt guard
+pub(crate) fn read_event() -> Event {
+    let event = crossterm::event::read().unwrap();
+    guard::handle_interrupt_event(&event);
 
-pub(crate) fn handle_key_input(buffer: &mut String, force: &mut bool) -> bool {
-    _handle_key_input(buffer, crossterm::event::read().unwrap(), force)
+    event
 }
 
+/// applies a key event to the buffer
+///
+/// supports the usual line editing keys:
+/// - Left/Right, Home/End (or Ctrl+A/Ctrl+E) to move the cursor
+/// - Alt+B/Alt+F (or Ctrl/Alt + Left/Right) to move by words
+/// - Backspace/Delete to delete around the cursor, Shift+Backspace to clear the line
+/// - Ctrl+W to delete the word before the cursor
+/// - Ctrl+U/Ctrl+K to delete everything before/after the cursor
+///
+/// # Returns
+///
+/// true if Enter was pressed
 #[inline]
-fn _handle_key_input(buffer: &mut String, event: Event, force: &mut bool) -> bool {
+pub(crate) fn handle_key_event(buffer: &mut LineBuffer, event: Event, force: &mut bool) -> bool {
     if let Event::Key(key_event) = event {
         let KeyEvent {
             code,
@@ -23,29 +110,46 @@ fn _handle_key_input(buffer: &mut String, event: Event, force: &mut bool) -> boo
             return false;
         }
 
-        return match code {
+        let word = modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+        match code {
             KeyCode::Enter => {
                 if modifiers.contains(KeyModifiers::SHIFT) {
                     *force = true;
                 } else {
                     return true;
                 }
-                false
             }
             KeyCode::Backspace => {
                 if modifiers.contains(KeyModifiers::SHIFT) {
                     buffer.clear();
                 } else {
-                    buffer.pop();
+                    buffer.backspace();
                 }
-                false
             }
-            KeyCode::Char(c) => {
-                buffer.push(c);
-                false
-            }
-            _ => false,
-        };
+            KeyCode::Delete => buffer.delete(),
+            KeyCode::Left if word => buffer.move_word_left(),
+            KeyCode::Right if word => buffer.move_word_right(),
+            KeyCode::Left => buffer.move_left(),
+            KeyCode::Right => buffer.move_right(),
+            KeyCode::Home => buffer.move_home(),
+            KeyCode::End => buffer.move_end(),
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::CONTROL) => match c {
+                'a' => buffer.move_home(),
+                'e' => buffer.move_end(),
+                'w' => buffer.delete_word_left(),
+                'u' => buffer.delete_to_start(),
+                'k' => buffer.delete_to_end(),
+                _ => {}
+            },
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::ALT) => match c {
+                'b' => buffer.move_word_left(),
+                'f' => buffer.move_word_right(),
+                _ => {}
+            },
+            KeyCode::Char(c) => buffer.insert(c),
+            _ => {}
+        }
     }
 
     false
@@ -59,39 +163,75 @@ mod tests {
 
     #[test]
     fn test_handle_key_input_windows_enter() {
-        let mut buffer = String::new();
+        let mut buffer = LineBuffer::new();
         let event = Event::Key(KeyEvent {
             code: KeyCode::Enter,
             modifiers: KeyModifiers::empty(),
             kind: KeyEventKind::Press,
             state: KeyEventState::empty(),
         });
-        assert!(_handle_key_input(&mut buffer, event, &mut false));
+        assert!(handle_key_event(&mut buffer, event, &mut false));
     }
 
     #[test]
     fn test_handle_key_input_windows_backspace() {
-        let mut buffer = String::from("test");
+        let mut buffer = LineBuffer::new();
+        buffer.set("test");
         let event = Event::Key(KeyEvent {
             code: KeyCode::Backspace,
             modifiers: KeyModifiers::empty(),
             kind: KeyEventKind::Press,
             state: KeyEventState::empty(),
         });
-        _handle_key_input(&mut buffer, event, &mut false);
-        assert_eq!(buffer, "tes");
+        handle_key_event(&mut buffer, event, &mut false);
+        assert_eq!(&*buffer, "tes");
     }
 
     #[test]
     fn test_handle_key_input_windows_char() {
-        let mut buffer = String::new();
+        let mut buffer = LineBuffer::new();
         let event = Event::Key(KeyEvent {
             code: KeyCode::Char('a'),
             modifiers: KeyModifiers::empty(),
             kind: KeyEventKind::Press,
             state: KeyEventState::empty(),
         });
-        _handle_key_input(&mut buffer, event, &mut false);
-        assert_eq!(buffer, "a");
+        handle_key_event(&mut buffer, event, &mut false);
+        assert_eq!(&*buffer, "a");
+    }
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> Event {
+        Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        })
+    }
+
+    #[test]
+    fn test_handle_key_input_editing() {
+        let mut buffer = LineBuffer::new();
+        buffer.set("hello world");
+
+        for event in [
+            press(KeyCode::Char('b'), KeyModifiers::ALT),
+            press(KeyCode::Left, KeyModifiers::empty()),
+            press(KeyCode::Char(','), KeyModifiers::empty()),
+            press(KeyCode::End, KeyModifiers::empty()),
+            press(KeyCode::Char('w'), KeyModifiers::CONTROL),
+            press(KeyCode::Char('W'), KeyModifiers::SHIFT),
+        ] {
+            assert!(!handle_key_event(&mut buffer, event, &mut false));
+        }
+
+        assert_eq!(&*buffer, "hello, W");
+
+        handle_key_event(
+            &mut buffer,
+            press(KeyCode::Char('u'), KeyModifiers::CONTROL),
+            &mut false,
+        );
+        assert!(buffer.is_empty());
     }
 }
@@ -47,12 +47,7 @@ lazy_static! {
 pub(crate) fn is_package_installed(package: &str) -> Result<(), io::Error> {
     let package_manager = match *pckgm::PACKAGE_MANAGER {
         Some(pm) => pm,
-        None => {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "No package manager detected",
-            ))
-        }
+        None => return Err(io::Error::other("No package manager detected")),
     };
 
     let output = match package_manager {
@@ -65,19 +60,14 @@ pub(crate) fn is_package_installed(package: &str) -> Result<(), io::Error> {
         "brew" => Command::new("brew").args(["list", package]).output(),
         "zypper" => Command::new("zypper").args(["se", "-i", package]).output(),
         "apk" => Command::new("apk").args(["info", package]).output(),
-        _ => {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Unsupported package manager",
-            ))
-        }
+        _ => return Err(io::Error::other("Unsupported package manager")),
     };
 
     match output {
         Ok(output) if output.status.success() => Ok(()),
-        _ => Err(io::Error::new(
-            io::ErrorKind::Other,
-            format!("Package '{}' is not installed", package),
-        )),
+        _ => Err(io::Error::other(format!(
+            "Package '{}' is not installed",
+            package
+        ))),
     }
 }
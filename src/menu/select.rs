@@ -0,0 +1,824 @@
+//! Selection Widgets
+//!
+//! **Note: ** This module is a work in progress,
+//! and breaking changes could be made soon without increasing the major version
+//! for different reasons, such as improvements or bug fixes.
+//!
+//! lists of options the user picks from with the arrow keys
+//!
+//! | key                   | action                          |
+//! |-----------------------|---------------------------------|
+//! | `↑` / `k`             | previous option                 |
+//! | `↓` / `j`             | next option                     |
+//! | `Home` / `End`        | first / last option             |
+//! | `PageUp` / `PageDown` | move by one page                |
+//! | `Space`               | toggle option ([`MultiSelect`]) |
+//! | `a`                   | toggle all ([`MultiSelect`])    |
+//! | `Enter`               | confirm                         |
+//!
+//! the options of a [`FuzzySelect`] are filtered by typing, so `j`, `k`, `a` and `Space`
+//! are part of the filter there
+
+use std::fmt::Display;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::menu::fuzzy::{fuzzy_match, Match};
+use crate::menu::list::ListState;
+use crate::menu::{begin_region, end_region, note, read_key};
+use crate::style::{combine_attributes, Attribute, Color, ContentStyle, StyledString};
+use crate::terminal::console_render::get_size;
+
+/// marker in front of the highlighted option
+const CURSOR: &str = "> ";
+
+/// number of options visible at once if not set otherwise
+const DEFAULT_PAGE_SIZE: usize = 7;
+
+/// style of the highlighted option
+fn highlight() -> ContentStyle {
+    ContentStyle {
+        foreground_color: Some(Color::Cyan),
+        attributes: combine_attributes(&[&Attribute::Bold]),
+        ..Default::default()
+    }
+}
+
+/// an option, highlighted and marked with the cursor if `highlighted`
+fn option(text: &str, highlighted: bool) -> StyledString {
+    if highlighted {
+        StyledString {
+            string: format!("{}{}", CURSOR, text),
+            style: highlight(),
+        }
+    } else {
+        StyledString::new(&format!("{}{}", " ".repeat(CURSOR.len()), text))
+    }
+}
+
+/// an option with the chars at the indices of `matched` highlighted
+fn fuzzy_option(text: &str, matched: &[usize], highlighted: bool) -> Vec<StyledString> {
+    let base = if highlighted {
+        highlight()
+    } else {
+        ContentStyle::default()
+    };
+    let matched_style = ContentStyle {
+        foreground_color: Some(Color::Yellow),
+        attributes: base.attributes | Attribute::Bold,
+        ..base
+    };
+
+    let mut parts = vec![StyledString {
+        string: if highlighted {
+            CURSOR.to_string()
+        } else {
+            " ".repeat(CURSOR.len())
+        },
+        style: base,
+    }];
+
+    // group runs of matched and unmatched chars
+    for (index, c) in text.chars().enumerate() {
+        let style = if matched.contains(&index) {
+            matched_style
+        } else {
+            base
+        };
+
+        match parts.last_mut() {
+            Some(last) if last.style == style => last.string.push(c),
+            _ => parts.push(StyledString {
+                string: c.to_string(),
+                style,
+            }),
+        }
+    }
+
+    parts
+}
+
+/// moves the cursor for the navigation keys of [`ListState::navigate`] and `j`/`k`
+fn navigate(state: &mut ListState, code: KeyCode) -> bool {
+    match code {
+        KeyCode::Char('k') => state.navigate(KeyCode::Up),
+        KeyCode::Char('j') => state.navigate(KeyCode::Down),
+        code => state.navigate(code),
+    }
+}
+
+/// shrinks the page size to the terminal height,
+/// leaving room for the title and the notes below the options
+fn fit_page(page_size: usize) -> usize {
+    let (_, rows) = get_size();
+    page_size.min((rows as usize).saturating_sub(5))
+}
+
+/// grey "- 3/40" note shown below the options if they don't fit on one page
+fn position_note(state: &ListState, len: usize) -> Option<Vec<StyledString>> {
+    if state.window().len() == len {
+        return None;
+    }
+
+    Some(note(&format!("{}/{}", state.cursor + 1, len)))
+}
+
+/// single choice menu
+///
+/// renders the options below the title, the highlighted one is confirmed with Enter
+///
+/// # Example
+///
+/// ```no_run
+/// use zenity::menu::select::Select;
+///
+/// let index = Select::new("Pick a color", vec!["red", "green", "blue"])
+///     .set_default(1)
+///     .start();
+///
+/// println!("picked option {}", index);
+/// ```
+pub struct Select<T> {
+    /// The title or prompt displayed above the options.
+    title: String,
+    /// The options to choose from.
+    items: Vec<T>,
+    /// The index of the option highlighted at the start.
+    default: usize,
+    /// The number of options visible at once.
+    page_size: usize,
+}
+
+impl<T: Display> Select<T> {
+    /// creates a new select menu with the given title and options
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::Select;
+    ///
+    /// let select = Select::new("Pick a color", vec!["red", "green", "blue"]);
+    /// ```
+    pub fn new(title: &str, items: impl IntoIterator<Item = T>) -> Self {
+        Select {
+            title: title.to_string(),
+            items: items.into_iter().collect(),
+            default: 0,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// sets the index of the option highlighted at the start
+    ///
+    /// out of range indices highlight the last option
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::Select;
+    ///
+    /// let select = Select::new("Pick a color", vec!["red", "green", "blue"]).set_default(2);
+    /// ```
+    pub fn set_default(mut self, index: usize) -> Self {
+        self.default = index;
+
+        self
+    }
+
+    /// sets the number of options visible at once, longer lists scroll
+    ///
+    /// the page is shrunk further if the terminal is too small
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::Select;
+    ///
+    /// let select = Select::new("Pick a number", 1..=100).set_page_size(10);
+    /// ```
+    pub fn set_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+
+        self
+    }
+
+    /// the options of the menu
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// starts the menu and blocks until an option is confirmed with Enter
+    ///
+    /// # Returns
+    ///
+    /// the index of the chosen option
+    ///
+    /// # Panics
+    ///
+    /// if the menu has no options
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use zenity::menu::select::Select;
+    ///
+    /// let select = Select::new("Pick a color", vec!["red", "green", "blue"]);
+    /// let color = select.items()[select.start()];
+    /// ```
+    pub fn start(&self) -> usize {
+        assert!(!self.items.is_empty(), "Select needs at least one option");
+
+        let mut state = ListState::new(self.items.len(), fit_page(self.page_size), self.default);
+
+        let mut region = begin_region();
+
+        loop {
+            region.update_size();
+            region.render(self.lines(&state));
+
+            let Some(key_event) = read_key() else {
+                continue;
+            };
+
+            if key_event.code == KeyCode::Enter {
+                break;
+            }
+
+            navigate(&mut state, key_event.code);
+        }
+
+        end_region(&[
+            StyledString::new(&format!("{} ", self.title)),
+            StyledString::simple(
+                &self.items[state.cursor].to_string(),
+                Some(Color::Green),
+                None,
+                None,
+            ),
+        ]);
+
+        state.cursor
+    }
+
+    /// starts the menu like [`Select::start`]
+    ///
+    /// # Returns
+    ///
+    /// the chosen option
+    pub fn start_value(&self) -> &T {
+        &self.items[self.start()]
+    }
+
+    fn lines(&self, state: &ListState) -> Vec<Vec<StyledString>> {
+        let mut lines = vec![vec![StyledString::new(&self.title)]];
+
+        for index in state.window() {
+            lines.push(vec![option(
+                &self.items[index].to_string(),
+                index == state.cursor,
+            )]);
+        }
+
+        lines.extend(position_note(state, self.items.len()));
+
+        lines
+    }
+}
+
+/// multiple choice menu
+///
+/// options are checked with Space (or all at once with `a`) and confirmed with Enter,
+/// Enter is ignored while the number of checked options is out of the allowed range
+///
+/// # Example
+///
+/// ```no_run
+/// use zenity::menu::select::MultiSelect;
+///
+/// let toppings = MultiSelect::new("Toppings", vec!["cheese", "ham", "olives", "onions"])
+///     .set_defaults(&[0])
+///     .set_max(3)
+///     .start();
+///
+/// println!("picked options {:?}", toppings);
+/// ```
+pub struct MultiSelect<T> {
+    /// The title or prompt displayed above the options.
+    title: String,
+    /// The options to choose from.
+    items: Vec<T>,
+    /// The indices of the options checked at the start.
+    defaults: Vec<usize>,
+    /// The minimum number of options that have to be checked.
+    min: usize,
+    /// The maximum number of options that can be checked.
+    max: Option<usize>,
+    /// The number of options visible at once.
+    page_size: usize,
+}
+
+impl<T: Display> MultiSelect<T> {
+    /// creates a new multi select menu with the given title and options
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::MultiSelect;
+    ///
+    /// let select = MultiSelect::new("Toppings", vec!["cheese", "ham", "olives"]);
+    /// ```
+    pub fn new(title: &str, items: impl IntoIterator<Item = T>) -> Self {
+        MultiSelect {
+            title: title.to_string(),
+            items: items.into_iter().collect(),
+            defaults: Vec::new(),
+            min: 0,
+            max: None,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// sets the indices of the options checked at the start
+    ///
+    /// out of range indices are ignored
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::MultiSelect;
+    ///
+    /// let select = MultiSelect::new("Toppings", vec!["cheese", "ham", "olives"]).set_defaults(&[0, 2]);
+    /// ```
+    pub fn set_defaults(mut self, indices: &[usize]) -> Self {
+        self.defaults = indices.to_vec();
+
+        self
+    }
+
+    /// sets the minimum number of options that have to be checked
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::MultiSelect;
+    ///
+    /// let select = MultiSelect::new("Toppings", vec!["cheese", "ham", "olives"]).set_min(1);
+    /// ```
+    pub fn set_min(mut self, min: usize) -> Self {
+        self.min = min;
+
+        self
+    }
+
+    /// sets the maximum number of options that can be checked
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::MultiSelect;
+    ///
+    /// let select = MultiSelect::new("Toppings", vec!["cheese", "ham", "olives"]).set_max(2);
+    /// ```
+    pub fn set_max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+
+        self
+    }
+
+    /// sets the number of options visible at once, longer lists scroll
+    ///
+    /// the page is shrunk further if the terminal is too small
+    pub fn set_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+
+        self
+    }
+
+    /// the options of the menu
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// starts the menu and blocks until the checked options are confirmed with Enter
+    ///
+    /// # Returns
+    ///
+    /// the indices of the checked options in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use zenity::menu::select::MultiSelect;
+    ///
+    /// let indices = MultiSelect::new("Toppings", vec!["cheese", "ham", "olives"]).start();
+    /// ```
+    pub fn start(&self) -> Vec<usize> {
+        let mut checked = vec![false; self.items.len()];
+        for &index in &self.defaults {
+            if let Some(checked) = checked.get_mut(index) {
+                *checked = true;
+            }
+        }
+
+        let mut state = ListState::new(self.items.len(), fit_page(self.page_size), 0);
+
+        let mut region = begin_region();
+
+        loop {
+            region.update_size();
+            region.render(self.lines(&state, &checked));
+
+            let Some(key_event) = read_key() else {
+                continue;
+            };
+
+            match key_event.code {
+                KeyCode::Enter if self.validate(&checked).is_none() => break,
+                KeyCode::Char(' ') => {
+                    if let Some(checked) = checked.get_mut(state.cursor) {
+                        *checked = !*checked;
+                    }
+                }
+                KeyCode::Char('a') => {
+                    let all = checked.iter().all(|&checked| checked);
+                    checked.iter_mut().for_each(|checked| *checked = !all);
+                }
+                code => {
+                    navigate(&mut state, code);
+                }
+            }
+        }
+
+        let indices = (0..self.items.len())
+            .filter(|&index| checked[index])
+            .collect::<Vec<_>>();
+
+        end_region(&[
+            StyledString::new(&format!("{} ", self.title)),
+            StyledString::simple(
+                &indices
+                    .iter()
+                    .map(|&index| self.items[index].to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Some(Color::Green),
+                None,
+                None,
+            ),
+        ]);
+
+        indices
+    }
+
+    /// starts the menu like [`MultiSelect::start`]
+    ///
+    /// # Returns
+    ///
+    /// the checked options
+    pub fn start_values(&self) -> Vec<&T> {
+        self.start()
+            .into_iter()
+            .map(|index| &self.items[index])
+            .collect()
+    }
+
+    /// checks the min/max constraints
+    ///
+    /// # Returns
+    ///
+    /// the note explaining the violated constraint, None if the selection is valid
+    fn validate(&self, checked: &[bool]) -> Option<String> {
+        let count = checked.iter().filter(|&&checked| checked).count();
+
+        if count < self.min {
+            Some(format!(
+                "Select at least {} option{}",
+                self.min,
+                if self.min == 1 { "" } else { "s" }
+            ))
+        } else {
+            self.max.filter(|&max| count > max).map(|max| {
+                format!(
+                    "Select at most {} option{}",
+                    max,
+                    if max == 1 { "" } else { "s" }
+                )
+            })
+        }
+    }
+
+    fn lines(&self, state: &ListState, checked: &[bool]) -> Vec<Vec<StyledString>> {
+        let mut lines = vec![vec![StyledString::new(&self.title)]];
+
+        for index in state.window() {
+            let check = if checked[index] { "[x] " } else { "[ ] " };
+
+            lines.push(vec![option(
+                &format!("{}{}", check, self.items[index]),
+                index == state.cursor,
+            )]);
+        }
+
+        lines.extend(position_note(state, self.items.len()));
+        lines.extend(self.validate(checked).map(|text| note(&text)));
+
+        lines
+    }
+}
+
+/// single choice menu filtered by typing
+///
+/// the options are matched fuzzily against the typed text (e.g. `fb` matches `foo-bar`),
+/// ranked by how well they match and the matched characters are highlighted
+///
+/// # Example
+///
+/// ```no_run
+/// use zenity::menu::select::FuzzySelect;
+///
+/// let branches = vec!["main", "develop", "feature/fuzzy-select", "fix/input-cursor"];
+///
+/// let index = FuzzySelect::new("Checkout branch", branches).start();
+/// ```
+pub struct FuzzySelect<T> {
+    /// The title or prompt displayed in front of the filter.
+    title: String,
+    /// The options to choose from.
+    items: Vec<T>,
+    /// The index of the option highlighted at the start.
+    default: usize,
+    /// The number of options visible at once.
+    page_size: usize,
+}
+
+impl<T: Display> FuzzySelect<T> {
+    /// creates a new fuzzy select menu with the given title and options
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::menu::select::FuzzySelect;
+    ///
+    /// let select = FuzzySelect::new("Host", vec!["alpha.example.com", "beta.example.com"]);
+    /// ```
+    pub fn new(title: &str, items: impl IntoIterator<Item = T>) -> Self {
+        FuzzySelect {
+            title: title.to_string(),
+            items: items.into_iter().collect(),
+            default: 0,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// sets the index of the option highlighted before anything is typed
+    pub fn set_default(mut self, index: usize) -> Self {
+        self.default = index;
+
+        self
+    }
+
+    /// sets the number of options visible at once, longer lists scroll
+    ///
+    /// the page is shrunk further if the terminal is too small
+    pub fn set_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+
+        self
+    }
+
+    /// the options of the menu
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// starts the menu and blocks until an option is confirmed with Enter
+    ///
+    /// Enter is ignored while no option matches the filter
+    ///
+    /// # Returns
+    ///
+    /// the index of the chosen option in the unfiltered options
+    ///
+    /// # Panics
+    ///
+    /// if the menu has no options
+    pub fn start(&self) -> usize {
+        assert!(
+            !self.items.is_empty(),
+            "FuzzySelect needs at least one option"
+        );
+
+        let page_size = fit_page(self.page_size);
+
+        let mut query = String::new();
+        let mut matches = self.filter(&query);
+        let mut state = ListState::new(matches.len(), page_size, self.default);
+
+        let mut region = begin_region();
+
+        let index = loop {
+            region.update_size();
+            region.render(self.lines(&query, &matches, &state));
+
+            let Some(key_event) = read_key() else {
+                continue;
+            };
+
+            match key_event.code {
+                KeyCode::Enter => {
+                    if let Some((index, _)) = matches.get(state.cursor) {
+                        break *index;
+                    }
+                    continue;
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                }
+                KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    query.push(c);
+                }
+                code => {
+                    state.navigate(code);
+                    continue;
+                }
+            }
+
+            // the filter changed, start at the best match
+            matches = self.filter(&query);
+            state = ListState::new(matches.len(), page_size, 0);
+        };
+
+        end_region(&[
+            StyledString::new(&format!("{} ", self.title)),
+            StyledString::simple(
+                &self.items[index].to_string(),
+                Some(Color::Green),
+                None,
+                None,
+            ),
+        ]);
+
+        index
+    }
+
+    /// starts the menu like [`FuzzySelect::start`]
+    ///
+    /// # Returns
+    ///
+    /// the chosen option
+    pub fn start_value(&self) -> &T {
+        &self.items[self.start()]
+    }
+
+    /// the indices and matches of the options matching `query`, best matches first
+    fn filter(&self, query: &str) -> Vec<(usize, Match)> {
+        let mut matches = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                fuzzy_match(query, &item.to_string()).map(|found| (index, found))
+            })
+            .collect::<Vec<_>>();
+
+        // stable, so equally good matches keep their order
+        matches.sort_by_key(|(_, found)| std::cmp::Reverse(found.score));
+
+        matches
+    }
+
+    fn lines(
+        &self,
+        query: &str,
+        matches: &[(usize, Match)],
+        state: &ListState,
+    ) -> Vec<Vec<StyledString>> {
+        let mut lines = vec![vec![
+            StyledString::new(&format!("{} ", self.title)),
+            StyledString::simple(query, Some(Color::Green), None, None),
+        ]];
+
+        for position in state.window() {
+            let (index, found) = &matches[position];
+
+            lines.push(fuzzy_option(
+                &self.items[*index].to_string(),
+                &found.indices,
+                position == state.cursor,
+            ));
+        }
+
+        lines.extend(position_note(state, matches.len()));
+
+        if matches.is_empty() {
+            lines.push(note("No matches"));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(lines: &[Vec<StyledString>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|part| part.string.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_lines() {
+        let select = Select::new("Pick", ["a", "b", "c"]).set_default(1);
+        let state = ListState::new(3, 7, select.default);
+
+        let lines = select.lines(&state);
+
+        assert_eq!(strings(&lines), vec!["Pick", "  a", "> b", "  c"]);
+        assert_eq!(lines[2][0].style, highlight());
+    }
+
+    #[test]
+    fn test_lines_scrolled() {
+        let select = Select::new("Pick", 1..=10);
+        let state = ListState::new(10, 3, 9);
+
+        assert_eq!(
+            strings(&select.lines(&state)),
+            vec!["Pick", "  8", "  9", "> 10", "- 10/10"]
+        );
+    }
+
+    #[test]
+    fn test_multi_select_lines() {
+        let select = MultiSelect::new("Pick", ["a", "b"]).set_min(2);
+        let state = ListState::new(2, 7, 1);
+
+        assert_eq!(
+            strings(&select.lines(&state, &[true, false])),
+            vec!["Pick", "  [x] a", "> [ ] b", "- Select at least 2 options"]
+        );
+    }
+
+    #[test]
+    fn test_multi_select_validate() {
+        let select = MultiSelect::new("Pick", ["a", "b", "c"])
+            .set_min(1)
+            .set_max(2);
+
+        assert_eq!(
+            select.validate(&[false, false, false]).unwrap(),
+            "Select at least 1 option"
+        );
+        assert_eq!(select.validate(&[true, false, true]), None);
+        assert_eq!(
+            select.validate(&[true, true, true]).unwrap(),
+            "Select at most 2 options"
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_select_filter() {
+        let select = FuzzySelect::new("Pick", ["foo-bar", "fab", "baz"]);
+
+        let order = |query| {
+            select
+                .filter(query)
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(""), vec![0, 1, 2]);
+        // "b" starts a word in "foo-bar"
+        assert_eq!(order("fb"), vec![0, 1]);
+        assert_eq!(order("fab"), vec![1]);
+        assert_eq!(order("z"), vec![2]);
+    }
+
+    #[test]
+    fn test_fuzzy_select_lines() {
+        let select = FuzzySelect::new("Pick", ["foo-bar", "baz"]);
+        let matches = select.filter("fb");
+        let state = ListState::new(matches.len(), 7, 0);
+
+        let lines = select.lines("fb", &matches, &state);
+
+        assert_eq!(strings(&lines), vec!["Pick fb", "> foo-bar"]);
+        assert_eq!(
+            lines[1]
+                .iter()
+                .map(|part| part.string.as_str())
+                .collect::<Vec<_>>(),
+            vec!["> ", "f", "oo-", "b", "ar"]
+        );
+        assert_eq!(lines[1][1].style.foreground_color, Some(Color::Yellow));
+
+        assert_eq!(
+            strings(&select.lines("x", &[], &ListState::new(0, 7, 0))),
+            vec!["Pick x", "- No matches"]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! temporary directories for the tests of the path requirements and completions
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// empty temporary directory, removed with its contents when dropped
+///
+/// dropping also happens while unwinding, so a failed assertion doesn't leave the directory behind
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    /// creates the directory, `name` keeps the directories of parallel tests apart
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("zenity-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        TempDir(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
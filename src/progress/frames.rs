@@ -10,7 +10,7 @@
 //!             limiter: styled_string![""],
 //!             bar_incomplete_char: styled_string!["-"],
 //!             end: styled_string!["]"],
-//!             size: 30,
+//!             size: Some(30),
 //!             goal: 100,
 //!             current: 0,
 //!         };
@@ -38,7 +38,7 @@ use crate::{
 ///             limiter: styled_string![""],
 ///             bar_incomplete_char: styled_string!["-"],
 ///             end: styled_string!["]"],
-///             size: 30,
+///             size: Some(30),
 ///             goal: 100,
 ///             current: 0,
 ///         };
@@ -46,11 +46,16 @@ use crate::{
 /// # assert_eq!(spinner_frames.bar_complete_char, styled_string!["="]);
 /// # assert_eq!(spinner_frames.bar_incomplete_char, styled_string!["-"]);
 /// # assert_eq!(spinner_frames.end, styled_string!["]"]);
-/// # assert_eq!(spinner_frames.size, 30);
+/// # assert_eq!(spinner_frames.size, Some(30));
 /// # assert_eq!(spinner_frames.goal, 100);
 /// # assert_eq!(spinner_frames.current, 0);
 /// ```
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Frames {
     /// begin string
     pub begin: Vec<StyledString>,
@@ -67,8 +72,9 @@ pub struct Frames {
     /// ending string
     pub end: Vec<StyledString>,
 
-    /// size of progress bar
-    pub size: usize,
+    /// size of progress bar, `None` fills the remaining terminal width
+    /// (see [`Frames::set_auto_size`])
+    pub size: Option<usize>,
 
     /// goal value
     pub goal: usize,
@@ -158,7 +164,7 @@ impl Frames {
             limiter,
             bar_incomplete_char,
             end,
-            size: 30,
+            size: Some(30),
             goal: 100,
             current: 0,
         }
@@ -168,7 +174,7 @@ impl Frames {
     /// # Arguments
     ///
     /// * `Size` - The size of the progress bar as an usize,
-    /// where 1 represents one character in the loading bar.
+    ///   where 1 represents one character in the loading bar.
     ///
     /// # Returns
     ///
@@ -180,10 +186,32 @@ impl Frames {
     /// use zenity::progress::Frames;
     ///
     /// let bar = Frames::default().set_size(20);
-    /// # assert_eq!(bar.size, 20);
+    /// # assert_eq!(bar.size, Some(20));
     /// ```
     pub fn set_size(&mut self, size: usize) -> Self {
-        self.size = size;
+        self.size = Some(size);
+
+        self.clone()
+    }
+
+    /// Lets the progress bar fill the remaining terminal width.
+    ///
+    /// The size is recomputed on every render, so the bar follows terminal resizes.
+    ///
+    /// # Returns
+    ///
+    /// A new Frames object filling the remaining terminal width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zenity::progress::Frames;
+    ///
+    /// let bar = Frames::default().set_auto_size();
+    /// # assert_eq!(bar.size, None);
+    /// ```
+    pub fn set_auto_size(&mut self) -> Self {
+        self.size = None;
 
         self.clone()
     }
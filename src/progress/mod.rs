@@ -14,18 +14,195 @@
 //! ```
 
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
-use std::thread;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
 
+pub use crate::sort::SortBy;
 pub use frames::*;
+#[cfg(feature = "rayon")]
+pub use par_iter::*;
 
 use crate::iterators::balanced_single;
 use crate::style::StyledString;
-use crate::terminal::console_render::{get_rows, push_content_up};
-use crate::terminal::{console_cursor, console_render};
+use crate::terminal::console_cursor;
+use crate::terminal::console_render::{
+    get_rows, push_content_up, LiveRegion, Pause, RenderLoop, SYNCHRONIZED_OUTPUT,
+};
 
 pub mod frames;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
+
+/// time between two frames of animated progress bars
+const FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// time between two renders
+const RENDER_INTERVAL: Duration = Duration::from_millis(50);
+
+/// bars of a [`ProgressBar`]
+///
+/// the bars are only written when adding bars,
+/// so updates and the render loop can read them at the same time
+type BarMap = Arc<RwLock<Bars>>;
+
+/// bars by uid, in insertion order
+#[derive(Default)]
+struct Bars {
+    map: HashMap<usize, Arc<Bar>>,
+    order: Vec<usize>,
+}
+
+impl Bars {
+    /// appends a bar with an incremental uid starting from 1
+    fn insert(&mut self, bar: Bar) -> usize {
+        let uid = self.map.len() + 1;
+
+        self.map.insert(uid, Arc::new(bar));
+        self.order.push(uid);
+
+        uid
+    }
+
+    /// inserts a bar right before or after `anchor`, or appends it if the anchor doesn't exist
+    fn insert_next_to(&mut self, anchor: usize, after: bool, bar: Bar) -> usize {
+        let index = self.order.iter().position(|uid| *uid == anchor);
+        let uid = self.insert(bar);
+
+        if let Some(index) = index {
+            self.order.pop();
+            self.order
+                .insert(if after { index + 1 } else { index }, uid);
+        }
+
+        uid
+    }
+
+    fn get(&self, uid: &usize) -> Option<&Arc<Bar>> {
+        self.map.get(uid)
+    }
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// the bars in render order
+    fn sorted(&self, sort: SortBy) -> Vec<&Arc<Bar>> {
+        let mut bars = self
+            .order
+            .iter()
+            .map(|uid| &self.map[uid])
+            .collect::<Vec<_>>();
+
+        // stable, so equal bars keep their insertion order
+        match sort {
+            // bars have no text to sort by
+            SortBy::Insertion | SortBy::Name => {}
+            SortBy::RunningFirst => bars.sort_by_key(|bar| bar.is_finished()),
+            SortBy::FinishedFirst => bars.sort_by_key(|bar| !bar.is_finished()),
+            SortBy::LeastProgress => bars.sort_by(|a, b| a.fraction().total_cmp(&b.fraction())),
+            SortBy::MostProgress => bars.sort_by(|a, b| b.fraction().total_cmp(&a.fraction())),
+        }
+
+        bars
+    }
+}
+
+/// a single progress bar
+///
+/// the position and the goal are atomics,
+/// so updates from many threads never wait for each other or for the render loop
+///
+/// the goal is read again after clamping the position to it,
+/// so a goal raised at the same time doesn't swallow increments
+struct Bar {
+    frames: Frames,
+    current: AtomicUsize,
+    goal: AtomicUsize,
+}
+
+impl Bar {
+    fn new(frames: Frames) -> Self {
+        Bar {
+            current: AtomicUsize::new(frames.current),
+            goal: AtomicUsize::new(frames.goal),
+            frames,
+        }
+    }
+
+    /// increments the current value, limited by the goal
+    fn inc(&self, num: usize) {
+        let mut num = num;
+
+        loop {
+            let mut goal = 0;
+            let mut lost = 0;
+
+            // the closure always returns `Some`, so this can't fail
+            let _ = self
+                .current
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                    goal = self.goal.load(Ordering::SeqCst);
+                    let wanted = current.saturating_add(num);
+                    lost = wanted - wanted.min(goal);
+
+                    Some(wanted.min(goal))
+                });
+
+            // retry what was cut off if the goal was raised in the meantime
+            if lost == 0 || self.goal.load(Ordering::SeqCst) <= goal {
+                return;
+            }
+
+            num = lost;
+        }
+    }
+
+    /// sets the current value, limited by the goal
+    ///
+    /// like incrementing by the difference, the value never decreases
+    fn set(&self, new_current: usize) {
+        loop {
+            let goal = self.goal.load(Ordering::SeqCst);
+
+            self.current
+                .fetch_max(new_current.min(goal), Ordering::SeqCst);
+
+            // setting again is harmless, so retry if the goal was raised in the meantime
+            if new_current <= goal || self.goal.load(Ordering::SeqCst) <= goal {
+                return;
+            }
+        }
+    }
+
+    /// sets the goal, lowering the current value if it exceeds the new goal
+    fn set_goal(&self, goal: usize) {
+        self.goal.store(goal, Ordering::SeqCst);
+        self.current.fetch_min(goal, Ordering::SeqCst);
+    }
+
+    /// sets the current value to the goal
+    fn finish(&self) {
+        self.current
+            .store(self.goal.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+
+    fn get(&self) -> usize {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.get() >= self.goal.load(Ordering::Relaxed)
+    }
+
+    /// completed fraction between 0 and 1
+    fn fraction(&self) -> f64 {
+        match self.goal.load(Ordering::Relaxed) {
+            0 => 1.0,
+            goal => self.get() as f64 / goal as f64,
+        }
+    }
+}
 
 /// struct holding multiple ProgressBars / Frames and the uid
 ///
@@ -56,9 +233,12 @@ pub mod frames;
 ///
 /// ```
 pub struct ProgressBar {
-    bar: Arc<Mutex<HashMap<usize, Frames>>>,
+    bar: BarMap,
+    synchronized_output: Arc<Mutex<bool>>,
+    sort: Arc<Mutex<SortBy>>,
     clear_type: Arc<Mutex<Option<u16>>>,
-    stop: Arc<Mutex<bool>>,
+    pause: Pause,
+    render_loop: RenderLoop,
 }
 
 impl Default for ProgressBar {
@@ -99,8 +279,11 @@ impl ProgressBar {
         console_cursor::save_hide_cursor();
 
         let progress = ProgressBar {
-            bar: Arc::new(Mutex::new(HashMap::new())),
-            stop: Arc::new(Mutex::new(false)),
+            bar: Arc::new(RwLock::new(Bars::default())),
+            pause: Pause::default(),
+            render_loop: RenderLoop::default(),
+            synchronized_output: Arc::new(Mutex::new(*SYNCHRONIZED_OUTPUT)),
+            sort: Arc::new(Mutex::new(SortBy::default())),
             clear_type: Arc::new(Mutex::new(Some(get_rows()))),
         };
 
@@ -129,12 +312,77 @@ impl ProgressBar {
     /// # assert_eq!(uid2, 2);
     /// ```
     pub fn add(&self, bar: Frames) -> usize {
-        let mut bar_map = self.bar.lock().unwrap();
-        let uid: usize = bar_map.len() + 1_usize; // Incremental UID starting from 1
+        self.bar.write().unwrap().insert(Bar::new(bar))
+    }
 
-        bar_map.insert(uid, bar);
+    /// adds a new progress bar right before another bar
+    ///
+    /// if the UID is invalid the bar is added at the end
+    ///
+    /// # Returns
+    ///
+    /// the UID assigned to the added progress bar
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::{Frames, ProgressBar};
+    ///
+    /// let progress = ProgressBar::new(Frames::default());
+    ///
+    /// // drawn above the first bar
+    /// let uid = progress.insert_before(&progress.get_last(), Frames::hash());
+    /// # assert_eq!(uid, 2);
+    /// ```
+    pub fn insert_before(&self, uid: &usize, bar: Frames) -> usize {
+        self.bar
+            .write()
+            .unwrap()
+            .insert_next_to(*uid, false, Bar::new(bar))
+    }
 
-        uid
+    /// adds a new progress bar right after another bar
+    ///
+    /// if the UID is invalid the bar is added at the end
+    ///
+    /// # Returns
+    ///
+    /// the UID assigned to the added progress bar
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::{Frames, ProgressBar};
+    ///
+    /// let progress = ProgressBar::new(Frames::default());
+    /// let first = progress.get_last();
+    /// progress.add(Frames::rect());
+    ///
+    /// // drawn between the two bars
+    /// progress.insert_after(&first, Frames::hash());
+    /// ```
+    pub fn insert_after(&self, uid: &usize, bar: Frames) -> usize {
+        self.bar
+            .write()
+            .unwrap()
+            .insert_next_to(*uid, true, Bar::new(bar))
+    }
+
+    /// sets the order the bars are drawn in
+    ///
+    /// the order is applied on every render, so e.g. with [`SortBy::RunningFirst`]
+    /// bars sink to the bottom as soon as they complete
+    ///
+    /// bars have no text, [`SortBy::Name`] keeps the insertion order
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::{ProgressBar, SortBy};
+    ///
+    /// let progress = ProgressBar::default();
+    ///
+    /// progress.sort_by(SortBy::MostProgress);
+    /// ```
+    pub fn sort_by(&self, sort: SortBy) {
+        *self.sort.lock().unwrap() = sort;
     }
 
     /// Sets the number of rows to clear in the terminal before starting the animation.
@@ -160,12 +408,86 @@ impl ProgressBar {
     ///
     /// # Parameters
     /// - `rows`: An optional `u16` specifying the number of rows to clear.
-    /// If `None`, no rows will be cleared.
+    ///   If `None`, no rows will be cleared.
     ///
     pub fn clear(&self, rows: Option<u16>) {
         *self.clear_type.lock().unwrap() = rows;
     }
 
+    /// enables or disables synchronized output (DEC mode 2026)
+    ///
+    /// when enabled each frame is wrapped in begin/end synchronized update sequences,
+    /// so terminals supporting it update all bars at once without tearing
+    ///
+    /// by default this is enabled if the terminal is detected as supporting it,
+    /// the detection can also be turned off by setting `ZENITY_SYNC_OUTPUT=0`
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    ///
+    /// progress.synchronized_output(false);
+    /// ```
+    pub fn synchronized_output(&self, enable: bool) {
+        *self.synchronized_output.lock().unwrap() = enable;
+    }
+
+    /// hides the bars and stops drawing them until [`ProgressBar::resume`] is called
+    ///
+    /// waits for the frame currently being drawn, so the terminal can be written to
+    /// right after this returns, see [`ProgressBar::suspend`] for a scoped version
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    ///
+    /// progress.pause();
+    /// println!("the bars are hidden");
+    /// progress.resume();
+    /// ```
+    pub fn pause(&self) {
+        self.pause.pause();
+    }
+
+    /// continues drawing the bars after [`ProgressBar::pause`]
+    ///
+    /// the bars are drawn below the output written while paused,
+    /// so the output should end with a newline
+    pub fn resume(&self) {
+        self.pause.resume();
+    }
+
+    /// hides the bars while running `f` and redraws them afterwards
+    ///
+    /// the bars are redrawn even if `f` panics
+    ///
+    /// useful to print output, ask the user (e.g. with `menu::input::Confirm`)
+    /// or run a child process in the middle of an operation
+    ///
+    /// # Returns
+    ///
+    /// the value returned by `f`
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    ///
+    /// let answer = progress.suspend(|| {
+    ///     println!("the bars are hidden");
+    ///     42
+    /// });
+    /// # assert_eq!(answer, 42);
+    /// ```
+    pub fn suspend<T>(&self, f: impl FnOnce() -> T) -> T {
+        self.pause.suspend(f)
+    }
+
     /// Set the current value
     ///
     /// # Arguments
@@ -176,9 +498,9 @@ impl ProgressBar {
     /// ### NOTE
     ///
     /// - If the UID is invalid, this function does nothing
-    /// -
-    /// This function locks the progress bar associated with the provided UID
-    /// and updates its current value incrementally
+    /// - This takes a shared lock on the bars to look up the UID,
+    ///   for hot loops use a [`ProgressHandle`], which updates the bar without any lock
+    /// - The value never decreases and is limited by the goal
     ///
     /// ## Example
     /// ```
@@ -193,10 +515,98 @@ impl ProgressBar {
     /// # assert_eq!(spinner.get(&uid), Some(50));
     /// ```
     pub fn set(&self, uid: &usize, new_current: &usize) {
-        if let Some(bar) = self.bar.lock().unwrap().get_mut(uid) {
-            let current = bar.current;
-            let diff = new_current.saturating_sub(current);
-            bar.inc(&diff);
+        if let Some(bar) = self.bar.read().unwrap().get(uid) {
+            bar.set(*new_current);
+        }
+    }
+
+    /// Increments the current value
+    ///
+    /// # Arguments
+    ///
+    /// * `Uid` - the unique identifier of the progress bar
+    /// * `num` - the amount to increment by
+    ///
+    /// If the UID is invalid, this function does nothing
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::{Frames, ProgressBar};
+    ///
+    /// let progress = ProgressBar::default();
+    /// let uid = progress.get_last();
+    ///
+    /// progress.inc(&uid, &5);
+    /// progress.inc(&uid, &5);
+    /// # assert_eq!(progress.get(&uid), Some(10));
+    /// ```
+    pub fn inc(&self, uid: &usize, num: &usize) {
+        if let Some(bar) = self.bar.read().unwrap().get(uid) {
+            bar.inc(*num);
+        }
+    }
+
+    /// Sets the goal value
+    ///
+    /// If the current value exceeds the new goal it is lowered to the goal,
+    /// if the UID is invalid, this function does nothing
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    /// let uid = progress.get_last();
+    ///
+    /// progress.set_goal(&uid, &253);
+    /// progress.set(&uid, &200);
+    /// # assert_eq!(progress.get(&uid), Some(200));
+    /// ```
+    pub fn set_goal(&self, uid: &usize, goal: &usize) {
+        if let Some(bar) = self.bar.read().unwrap().get(uid) {
+            bar.set_goal(*goal);
+        }
+    }
+
+    /// Completes a progress bar by setting the current value to the goal
+    ///
+    /// If the UID is invalid, this function does nothing
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    /// let uid = progress.get_last();
+    ///
+    /// progress.finish(&uid);
+    /// # assert_eq!(progress.get(&uid), Some(100));
+    /// ```
+    pub fn finish(&self, uid: &usize) {
+        if let Some(bar) = self.bar.read().unwrap().get(uid) {
+            bar.finish();
+        }
+    }
+
+    /// returns a cheap handle to a progress bar
+    ///
+    /// the handle can be cloned and moved into other threads or tasks,
+    /// if the uid is invalid calls on the handle do nothing
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    ///
+    /// let handle = progress.handle(&progress.get_last());
+    /// handle.set(&50);
+    /// # assert_eq!(progress.get(&progress.get_last()), Some(50));
+    /// ```
+    pub fn handle(&self, uid: &usize) -> ProgressHandle {
+        ProgressHandle {
+            bar: self.bar.read().unwrap().get(uid).cloned(),
+            uid: *uid,
         }
     }
 
@@ -226,12 +636,7 @@ impl ProgressBar {
     /// # assert_eq!(spinner.get(&uid), Some(60));
     /// ```
     pub fn get(&self, uid: &usize) -> Option<usize> {
-        if let Some(bar) = self.bar.lock().unwrap().get(uid) {
-            let current = bar.current;
-            Some(current)
-        } else {
-            None
-        }
+        self.bar.read().unwrap().get(uid).map(|bar| bar.get())
     }
 
     /// start each queued progressbar
@@ -252,56 +657,65 @@ impl ProgressBar {
             push_content_up(rows);
         }
 
-        let bars = Arc::clone(&self.bar);
-        let stop = Arc::clone(&self.stop);
-
-        let mut last_render_time = Instant::now();
-
-        thread::spawn(move || {
-            let mut frame_index = 0_usize;
-            while !*stop.lock().unwrap() {
-                for (index, frames) in bars.lock().unwrap().iter() {
-                    let mut line = Vec::new();
-
-                    let size: usize = frames.size;
-                    let goal = frames.goal;
-                    let current: usize = frames.current;
+        let mut renderer = self.renderer();
+        self.render_loop
+            .spawn_thread(RENDER_INTERVAL, move || renderer.render());
+    }
 
-                    // calculate percentage completion
-                    let completion_percentage = (current as f64 / goal as f64) * 100.0;
+    /// start each queued progressbar on the current tokio runtime
+    ///
+    /// works like [`ProgressBar::run_all`] but the bars are drawn by a runtime task
+    /// instead of a dedicated thread
+    ///
+    /// dropping the progress bar stops the task without waiting for it,
+    /// the returned handle finishes once the final values are drawn and the cursor is restored
+    ///
+    /// # Panics
+    ///
+    /// if called outside of a tokio runtime, like [`tokio::spawn`]
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::{Frames, ProgressBar};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let progress = ProgressBar::new(Frames::default());
+    /// let handle = progress.handle(&progress.get_last());
+    ///
+    /// progress.run_async();
+    ///
+    /// for _ in 0..10 {
+    ///     handle.inc(&10);
+    ///     tokio::task::yield_now().await;
+    /// }
+    /// # assert_eq!(handle.get(), Some(100));
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn run_async(&self) -> tokio::task::JoinHandle<()> {
+        // panic before touching the terminal
+        let runtime = tokio::runtime::Handle::current();
 
-                    // calculate the number of characters to represent the completion percentage
-                    let complete_size = ((completion_percentage / 100.0) * size as f64) as usize;
-                    let incomplete_size = size - complete_size;
+        if let Some(rows) = *self.clear_type.lock().unwrap() {
+            push_content_up(rows);
+        }
 
-                    // check if it's time to update the frame index
-                    if Instant::now() - last_render_time >= Duration::from_millis(100) {
-                        frame_index += 1; // go to the next frame for animated progress bars
-                        last_render_time = Instant::now(); // update last render time
-                    }
+        let mut renderer = self.renderer();
+        self.render_loop
+            .spawn_task(&runtime, RENDER_INTERVAL, move || renderer.render())
+    }
 
-                    line.push(balanced_single(frame_index, &frames.begin.clone()).clone());
-                    line.push(
-                        balanced_single(frame_index, &frames.bar_complete_char.clone())
-                            .repeat(complete_size),
-                    );
-                    line.push(balanced_single(frame_index, &frames.limiter.clone()).clone());
-                    line.push(
-                        balanced_single(frame_index, &frames.bar_incomplete_char.clone())
-                            .repeat(incomplete_size),
-                    );
-                    line.push(balanced_single(frame_index, &frames.end.clone()).clone());
-                    line.push(StyledString::new(&format!(" {:.2}", completion_percentage)));
-                    line.push(StyledString::new("%"));
-                    line.push(StyledString::new(" | "));
-                    line.push(StyledString::new(&format!("{}", current)));
-                    line.push(StyledString::new("/"));
-                    line.push(StyledString::new(&format!("{}", goal)));
-
-                    console_render::render_styled_line(*index as u16, &line);
-                }
-            }
-        });
+    fn renderer(&self) -> ProgressRenderer {
+        ProgressRenderer {
+            bars: Arc::clone(&self.bar),
+            synchronized_output: Arc::clone(&self.synchronized_output),
+            sort: Arc::clone(&self.sort),
+            frame_index: 0,
+            last_render_time: Instant::now(),
+            pause: self.pause.clone(),
+            region: LiveRegion::new(),
+        }
     }
 
     /// retrieves the UID of the last created progress bar
@@ -319,17 +733,306 @@ impl ProgressBar {
     /// # assert_eq!(uid1, 1);
     /// ```
     pub fn get_last(&self) -> usize {
-        let bar_map = self.bar.lock().unwrap();
+        let bar_map = self.bar.read().unwrap();
         bar_map.len()
     }
 }
 
+/// state of the render loop, shared by [`ProgressBar::run_all`] and `ProgressBar::run_async`
+struct ProgressRenderer {
+    bars: BarMap,
+    synchronized_output: Arc<Mutex<bool>>,
+    sort: Arc<Mutex<SortBy>>,
+    frame_index: usize,
+    last_render_time: Instant,
+    pause: Pause,
+    region: LiveRegion,
+}
+
+impl ProgressRenderer {
+    /// draws all progress bars
+    fn render(&mut self) {
+        // held while drawing, so pausing waits for this frame
+        let pause = self.pause.clone();
+        let mut pause_state = pause.lock();
+        if pause_state.paused {
+            return;
+        }
+        if std::mem::take(&mut pause_state.resumed) {
+            self.region.reanchor();
+        }
+
+        let (cols, _rows) = self.region.update_size();
+        self.region
+            .set_synchronized(*self.synchronized_output.lock().unwrap());
+
+        // check if it's time to update the frame index
+        if Instant::now() - self.last_render_time >= FRAME_INTERVAL {
+            self.frame_index += 1; // go to the next frame for animated progress bars
+            self.last_render_time = Instant::now(); // update last render time
+        }
+
+        let sort = *self.sort.lock().unwrap();
+        let bar_map = self.bars.read().unwrap();
+
+        let lines = bar_map
+            .sorted(sort)
+            .into_iter()
+            .map(|bar| {
+                let current = bar.get();
+                let goal = bar.goal.load(Ordering::Relaxed);
+
+                render_bar(&bar.frames, current, goal, self.frame_index, cols)
+            })
+            .collect::<Vec<_>>();
+        drop(bar_map);
+
+        self.region.render(lines);
+    }
+}
+
+impl Drop for ProgressRenderer {
+    /// draws the final values before the bars are left behind when the render loop ends
+    fn drop(&mut self) {
+        self.render();
+
+        console_cursor::reset_cursor();
+        console_cursor::next_line(self.bars.read().unwrap().len() as u16);
+    }
+}
+
+/// builds the line of a single progress bar
+fn render_bar(
+    frames: &Frames,
+    current: usize,
+    goal: usize,
+    frame_index: usize,
+    cols: u16,
+) -> Vec<StyledString> {
+    let mut line = Vec::new();
+
+    // calculate percentage completion
+    let completion_percentage = (current as f64 / goal as f64) * 100.0;
+
+    let suffix = vec![
+        StyledString::new(&format!(" {:.2}", completion_percentage)),
+        StyledString::new("%"),
+        StyledString::new(" | "),
+        StyledString::new(&format!("{}", current)),
+        StyledString::new("/"),
+        StyledString::new(&format!("{}", goal)),
+    ];
+
+    let begin = balanced_single(frame_index, &frames.begin).clone();
+    let limiter = balanced_single(frame_index, &frames.limiter).clone();
+    let end = balanced_single(frame_index, &frames.end).clone();
+
+    let size = frames.size.unwrap_or_else(|| {
+        let used = [&begin, &limiter, &end]
+            .into_iter()
+            .chain(suffix.iter())
+            .map(StyledString::width)
+            .sum::<usize>();
+
+        (cols as usize).saturating_sub(used).max(1)
+    });
+
+    // calculate the number of characters to represent the completion percentage
+    let complete_size = ((completion_percentage / 100.0) * size as f64) as usize;
+    let incomplete_size = size - complete_size;
+
+    line.push(begin);
+    line.push(balanced_single(frame_index, &frames.bar_complete_char).repeat(complete_size));
+    line.push(limiter);
+    line.push(balanced_single(frame_index, &frames.bar_incomplete_char).repeat(incomplete_size));
+    line.push(end);
+    line.extend(suffix);
+
+    line
+}
+
+/// cheap handle to a single bar of a [`ProgressBar`]
+///
+/// handles are `Send + Sync` and can be cloned and moved into other threads or tasks,
+/// dropping a handle doesn't affect the bar
+///
+/// unlike the methods of [`ProgressBar`], updates through a handle skip the lock on the bars
+/// and only touch the atomics of the bar, which makes them the fastest way
+/// to report progress from many threads
+///
+/// # Example
+///
+/// ```
+/// use std::thread;
+/// use zenity::progress::ProgressBar;
+///
+/// let progress = ProgressBar::default();
+/// let handle = progress.handle(&progress.get_last());
+///
+/// thread::spawn(move || handle.set(&50)).join().unwrap();
+/// # assert_eq!(progress.get(&progress.get_last()), Some(50));
+/// ```
+#[derive(Clone)]
+pub struct ProgressHandle {
+    bar: Option<Arc<Bar>>,
+    uid: usize,
+}
+
+impl ProgressHandle {
+    /// unique identifier of the progress bar
+    pub fn uid(&self) -> usize {
+        self.uid
+    }
+
+    /// set the current value
+    ///
+    /// see [`ProgressBar::set`]
+    pub fn set(&self, new_current: &usize) {
+        if let Some(bar) = &self.bar {
+            bar.set(*new_current);
+        }
+    }
+
+    /// increments the current value
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::progress::ProgressBar;
+    ///
+    /// let progress = ProgressBar::default();
+    /// let handle = progress.handle(&progress.get_last());
+    ///
+    /// handle.inc(&5);
+    /// # assert_eq!(handle.get(), Some(5));
+    /// ```
+    pub fn inc(&self, num: &usize) {
+        if let Some(bar) = &self.bar {
+            bar.inc(*num);
+        }
+    }
+
+    /// sets the goal value
+    ///
+    /// see [`ProgressBar::set_goal`]
+    pub fn set_goal(&self, goal: &usize) {
+        if let Some(bar) = &self.bar {
+            bar.set_goal(*goal);
+        }
+    }
+
+    /// completes the bar by setting the current value to the goal
+    ///
+    /// see [`ProgressBar::finish`]
+    pub fn finish(&self) {
+        if let Some(bar) = &self.bar {
+            bar.finish();
+        }
+    }
+
+    /// get the current value
+    ///
+    /// see [`ProgressBar::get`]
+    pub fn get(&self) -> Option<usize> {
+        self.bar.as_ref().map(|bar| bar.get())
+    }
+}
+
 impl Drop for ProgressBar {
     /// stops the thread when the object is dropped
+    ///
+    /// waits for the final render of [`ProgressBar::run_all`],
+    /// see [`ProgressBar::run_async`] for the runtime task
     fn drop(&mut self) {
-        *self.stop.lock().unwrap() = true;
-        // cleanup methods
-        console_cursor::reset_cursor();
-        console_cursor::next_line(self.bar.lock().unwrap().len() as u16);
+        // a running loop leaves the cursor below the bars after its final render
+        if !self.render_loop.stop() {
+            console_cursor::reset_cursor();
+            console_cursor::next_line(self.bar.read().unwrap().len() as u16);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_bar_parallel_inc() {
+        let bar = Arc::new(Bar::new(Frames::default()));
+        bar.set_goal(8_000);
+
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..1_000 {
+                        bar.inc(1);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(bar.get(), 8_000);
+    }
+
+    #[test]
+    fn test_bar_limits() {
+        let bar = Bar::new(Frames::default());
+
+        bar.inc(150);
+        assert_eq!(bar.get(), 100);
+
+        bar.set(20);
+        assert_eq!(bar.get(), 100);
+
+        bar.set_goal(50);
+        assert_eq!(bar.get(), 50);
+    }
+
+    #[test]
+    fn test_bars_order() {
+        let mut bars = Bars::default();
+        for current in [30, 100, 60] {
+            bars.insert(Bar::new(Frames {
+                current,
+                ..Default::default()
+            }));
+        }
+        bars.insert_next_to(1, false, Bar::new(Frames::default()));
+        bars.insert_next_to(2, true, Bar::new(Frames::default()));
+
+        let order = |sort| {
+            bars.sorted(sort)
+                .into_iter()
+                .map(|bar| bar.get())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(bars.order, vec![4, 1, 2, 5, 3]);
+        assert_eq!(order(SortBy::Insertion), vec![0, 30, 100, 0, 60]);
+        assert_eq!(order(SortBy::RunningFirst), vec![0, 30, 0, 60, 100]);
+        assert_eq!(order(SortBy::FinishedFirst), vec![100, 0, 30, 0, 60]);
+        assert_eq!(order(SortBy::LeastProgress), vec![0, 0, 30, 60, 100]);
+        assert_eq!(order(SortBy::MostProgress), vec![100, 60, 30, 0, 0]);
+
+        // bars have no text, so this keeps the insertion order
+        assert_eq!(order(SortBy::Name), order(SortBy::Insertion));
+    }
+
+    #[test]
+    fn test_render_bar_size() {
+        use crate::style::line_width;
+
+        let auto = Frames::default().set_auto_size();
+        assert_eq!(line_width(&render_bar(&auto, 50, 100, 0, 80)), 80);
+        assert_eq!(line_width(&render_bar(&auto, 50, 100, 0, 60)), 60);
+
+        // a size of 0 is an empty bar, not an automatic size
+        let empty = Frames::default().set_size(0);
+        let full = Frames::default().set_size(10);
+        assert_eq!(
+            line_width(&render_bar(&full, 50, 100, 0, 80)),
+            line_width(&render_bar(&empty, 50, 100, 0, 80)) + 10
+        );
     }
 }
@@ -0,0 +1,206 @@
+//! Rayon integration for progress bars
+//!
+//! Adds `.progress()` to rayon's parallel iterators, reporting each processed item
+//! to a single progress bar shared by all worker threads
+//!
+//! ```
+//! use rayon::prelude::*;
+//! use zenity::progress::ParallelProgressIterator;
+//!
+//! let sum: u32 = (0..1_000_u32).into_par_iter().progress().map(|x| x * 2).sum();
+//! # assert_eq!(sum, 999_000);
+//! ```
+
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use crate::progress::{Frames, ProgressBar, ProgressHandle};
+
+/// extension trait adding progress reporting to rayon's parallel iterators
+///
+/// the bar is incremented once per item from the worker threads
+/// and finished (set to its goal) once the parallel iteration completes
+pub trait ParallelProgressIterator: ParallelIterator + Sized {
+    /// reports the progress to an existing progress bar
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use zenity::progress::{Frames, ParallelProgressIterator, ProgressBar};
+    ///
+    /// let progress = ProgressBar::default();
+    /// let uid = progress.add(Frames::hash());
+    /// progress.set_goal(&uid, &500);
+    ///
+    /// (0..500).into_par_iter()
+    ///     .progress_with(progress.handle(&uid))
+    ///     .for_each(|_| {
+    ///         // do work...
+    ///     });
+    /// # assert_eq!(progress.get(&uid), Some(500));
+    /// ```
+    fn progress_with(self, handle: ProgressHandle) -> ProgressParIter<Self> {
+        ProgressParIter {
+            iter: self,
+            target: Target::Handle(handle),
+        }
+    }
+
+    /// reports the progress to a new progress bar with `len` as goal
+    ///
+    /// useful if the iterator doesn't know its length,
+    /// the bar is created once the iterator is driven,
+    /// finished and rendered a final time once the parallel iteration completes,
+    /// it stays on screen like any dropped [`ProgressBar`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use zenity::progress::ParallelProgressIterator;
+    ///
+    /// let files = vec!["a.txt", "b.txt", "c.txt"];
+    ///
+    /// let lengths: Vec<usize> = files
+    ///     .par_iter()
+    ///     .filter(|name| name.starts_with('a'))
+    ///     .progress_count(files.len())
+    ///     .map(|name| name.len())
+    ///     .collect();
+    /// # assert_eq!(lengths, vec![5]);
+    /// ```
+    fn progress_count(self, len: usize) -> ProgressParIter<Self> {
+        ProgressParIter {
+            iter: self,
+            target: Target::Count(len),
+        }
+    }
+
+    /// reports the progress to a new progress bar with the length of the iterator as goal
+    ///
+    /// the bar is finished and stays on screen once the parallel iteration completes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use zenity::progress::ParallelProgressIterator;
+    ///
+    /// let squares: Vec<u32> = (0..100_u32).into_par_iter().progress().map(|x| x * x).collect();
+    /// # assert_eq!(squares.len(), 100);
+    /// ```
+    fn progress(self) -> ProgressParIter<Self>
+    where
+        Self: IndexedParallelIterator,
+    {
+        let len = self.len();
+        self.progress_count(len)
+    }
+}
+
+impl<I: ParallelIterator> ParallelProgressIterator for I {}
+
+/// parallel iterator reporting each item to a progress bar
+///
+/// created by the methods of [`ParallelProgressIterator`]
+pub struct ProgressParIter<I> {
+    iter: I,
+    target: Target,
+}
+
+/// the bar a [`ProgressParIter`] reports to
+enum Target {
+    /// bar of an existing progress bar (see [`ParallelProgressIterator::progress_with`])
+    Handle(ProgressHandle),
+    /// goal of the bar owned by the iterator (see [`ParallelProgressIterator::progress_count`])
+    Count(usize),
+}
+
+impl<I> ProgressParIter<I> {
+    /// creates and starts the owned bar and finishes the bar once `drive` completes
+    fn run<R>(self, drive: impl FnOnce(I, &ProgressHandle) -> R) -> R {
+        // the owned bar hides the cursor, so it's only created once the iterator is driven
+        let (bar, handle) = match self.target {
+            Target::Handle(handle) => (None, handle),
+            Target::Count(len) => {
+                let bar = ProgressBar::new(Frames {
+                    goal: len,
+                    ..Default::default()
+                });
+                let handle = bar.handle(&bar.get_last());
+                bar.run_all();
+
+                (Some(bar), handle)
+            }
+        };
+
+        let result = drive(self.iter, &handle);
+        handle.finish();
+
+        // dropping the owned bar draws the final state and moves the cursor below it
+        drop(bar);
+
+        result
+    }
+}
+
+impl<I: ParallelIterator> ParallelIterator for ProgressParIter<I> {
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.run(|iter, handle| iter.inspect(|_| handle.inc(&1)).drive_unindexed(consumer))
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.iter.opt_len()
+    }
+}
+
+impl<I: IndexedParallelIterator> IndexedParallelIterator for ProgressParIter<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.run(|iter, handle| iter.inspect(|_| handle.inc(&1)).drive(consumer))
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.run(|iter, handle| iter.inspect(|_| handle.inc(&1)).with_producer(callback))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_finished_after_drive() {
+        let progress = ProgressBar::default();
+        let handle = progress.handle(&progress.get_last());
+        handle.set_goal(&10);
+
+        // only 5 items reach the bar, finishing it has to fill the rest
+        (0..10)
+            .into_par_iter()
+            .filter(|x| x % 2 == 0)
+            .progress_with(handle.clone())
+            .for_each(|_| {});
+
+        assert_eq!(handle.get(), Some(10));
+    }
+
+    #[test]
+    fn test_bar_created_when_driven() {
+        let iter = (0..10).into_par_iter().progress();
+        assert!(matches!(iter.target, Target::Count(10)));
+
+        assert_eq!(iter.sum::<i32>(), 45);
+    }
+}
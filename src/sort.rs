@@ -0,0 +1,54 @@
+//! Order of the lines of the live containers
+//!
+//! [`SortBy`] is shared by [`MultiSpinner`](crate::spinner::MultiSpinner) and
+//! [`ProgressBar`](crate::progress::ProgressBar), variants that don't apply to a container
+//! keep the insertion order
+//!
+//! ```
+//! use zenity::sort::SortBy;
+//!
+//! let sort = SortBy::default();
+//! # assert_eq!(sort, SortBy::Insertion);
+//! ```
+
+/// order of the lines of a container
+///
+/// | variant                              | spinners | progress bars |
+/// |--------------------------------------|----------|---------------|
+/// | `Insertion`                          | yes      | yes           |
+/// | `RunningFirst`, `FinishedFirst`      | yes      | yes           |
+/// | `LeastProgress`, `MostProgress`      | no       | yes           |
+/// | `Name`                               | yes      | no            |
+///
+/// spinners are only reordered among their siblings, children always stay below their parent
+///
+/// # Example
+///
+/// ```
+/// use zenity::progress::ProgressBar;
+/// use zenity::sort::SortBy;
+/// use zenity::spinner::MultiSpinner;
+///
+/// let spinner = MultiSpinner::new();
+/// let progress = ProgressBar::default();
+///
+/// // finished lines sink to the bottom of both containers
+/// spinner.sort_by(SortBy::RunningFirst);
+/// progress.sort_by(SortBy::RunningFirst);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    /// the order the lines were added (or inserted) in
+    #[default]
+    Insertion,
+    /// running spinners and incomplete bars first, finished ones last
+    RunningFirst,
+    /// finished spinners and completed bars first, running ones last
+    FinishedFirst,
+    /// ascending by the completed fraction, only for progress bars
+    LeastProgress,
+    /// descending by the completed fraction, only for progress bars
+    MostProgress,
+    /// alphabetically by text, only for spinners (progress bars have no text)
+    Name,
+}
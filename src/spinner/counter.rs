@@ -0,0 +1,209 @@
+//! step counter drawn in front of the spinners of a [`MultiSpinner`](super::MultiSpinner)
+
+use std::time::Instant;
+
+use crate::style;
+use crate::style::{Attribute, Color, ContentStyle, StyledString};
+
+/// counter showing which step of a task list a spinner is, e.g. `[3/7]`
+///
+/// only top level spinners are counted, children are indented below the counter
+///
+/// # Example
+///
+/// ```
+/// use zenity::spinner::{Frames, MultiSpinner, StepCounter};
+/// use zenity::style::{Color, ContentStyle};
+///
+/// let spinner = MultiSpinner::new();
+/// spinner.add(Frames::default());
+/// spinner.add(Frames::default());
+///
+/// spinner.step_counter(
+///     StepCounter::new()
+///         .set_format("step {step} of {total}")
+///         .set_style(ContentStyle {
+///             foreground_color: Some(Color::Cyan),
+///             ..Default::default()
+///         })
+///         .set_total(7),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct StepCounter {
+    format: String,
+    style: ContentStyle,
+    total: Option<usize>,
+    count_finished: bool,
+}
+
+impl Default for StepCounter {
+    /// creates a counter formatted like `[3/7]` in grey italic
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::StepCounter;
+    ///
+    /// let counter = StepCounter::default();
+    /// ```
+    fn default() -> Self {
+        StepCounter {
+            format: "[{step}/{total}]".to_string(),
+            style: ContentStyle {
+                foreground_color: Some(Color::Grey),
+                attributes: style::combine_attributes(&[&Attribute::Italic]),
+                ..Default::default()
+            },
+            total: None,
+            count_finished: false,
+        }
+    }
+}
+
+impl StepCounter {
+    /// creates a new counter, see [`StepCounter::default`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// sets the format of the counter
+    ///
+    /// `{step}` and `{total}` are replaced with the step and the total number of steps
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::StepCounter;
+    ///
+    /// let counter = StepCounter::new().set_format("({step} of {total})");
+    /// ```
+    pub fn set_format(&mut self, format: &str) -> Self {
+        self.format = format.to_string();
+
+        self.clone()
+    }
+
+    /// sets the style of the counter
+    pub fn set_style(&mut self, style: ContentStyle) -> Self {
+        self.style = style;
+
+        self.clone()
+    }
+
+    /// sets the total number of steps
+    ///
+    /// by default the total is the number of top level spinners,
+    /// set it if not all steps are added upfront
+    ///
+    /// the total never drops below the number of top level spinners,
+    /// so the step of each spinner is shown as it is
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::StepCounter;
+    ///
+    /// let counter = StepCounter::new().set_total(7);
+    /// ```
+    pub fn set_total(&mut self, total: usize) -> Self {
+        self.total = Some(total);
+
+        self.clone()
+    }
+
+    /// counts only finished spinners
+    ///
+    /// finished spinners are numbered in the order they finished,
+    /// running spinners show the step that is currently worked on
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::StepCounter;
+    ///
+    /// let counter = StepCounter::new().count_finished();
+    /// ```
+    pub fn count_finished(&mut self) -> Self {
+        self.count_finished = true;
+
+        self.clone()
+    }
+
+    /// the step of each top level spinner
+    ///
+    /// `finished` holds the finish time of each spinner in render order
+    pub(crate) fn steps(&self, finished: &[Option<Instant>]) -> Vec<usize> {
+        if !self.count_finished {
+            return (1..=finished.len()).collect();
+        }
+
+        let finished_count = finished.iter().flatten().count();
+
+        finished
+            .iter()
+            .enumerate()
+            .map(|(index, time)| match time {
+                // earlier finished spinners (ties broken by position) come first
+                Some(time) => {
+                    1 + finished
+                        .iter()
+                        .enumerate()
+                        .filter(|(other_index, other)| match other {
+                            Some(other) => (other, other_index) < (time, &index),
+                            None => false,
+                        })
+                        .count()
+                }
+                None => finished_count + 1,
+            })
+            .collect()
+    }
+
+    /// the counter of a single spinner
+    pub(crate) fn render(&self, step: usize, spinners: usize) -> StyledString {
+        let total = self.total.map_or(spinners, |total| total.max(spinners));
+
+        StyledString {
+            string: self
+                .format
+                .replace("{step}", &step.to_string())
+                .replace("{total}", &total.to_string()),
+            style: self.style,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_steps() {
+        let counter = StepCounter::new();
+        let now = Instant::now();
+
+        assert_eq!(counter.steps(&[None, Some(now), None]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_steps_count_finished() {
+        let counter = StepCounter::new().count_finished();
+        let now = Instant::now();
+        let later = now + Duration::from_secs(1);
+
+        assert_eq!(
+            counter.steps(&[Some(later), None, Some(now), None]),
+            vec![2, 3, 1, 3]
+        );
+        assert_eq!(counter.steps(&[Some(now), Some(now)]), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut counter = StepCounter::new().set_format("{step} of {total}");
+
+        assert_eq!(counter.render(3, 7).string, "3 of 7");
+        assert_eq!(counter.set_total(9).render(3, 7).string, "3 of 9");
+        // more spinners than the set total
+        assert_eq!(counter.set_total(2).render(3, 7).string, "3 of 7");
+    }
+}
@@ -8,6 +8,7 @@
 //!         speed_ms: 100,
 //!         text: StyledString{string: "".to_string(),style: Default::default()},
 //!         stop: true,
+//!         ..Default::default()
 //!  };
 //! # assert_eq!(frames.frames, styled_string!["◐", "◓", "◑", "◒"]);
 //! # assert_eq!(frames.speed_ms, 100);
@@ -30,11 +31,17 @@ use crate::styled_string;
 ///         speed_ms: 100,
 ///         text: StyledString{string: "".to_string(),style: Default::default()},
 ///         stop: true,
+///         ..Default::default()
 ///  };
 /// # assert_eq!(frames.frames, styled_string!["◐", "◓", "◑", "◒"]);
 /// # assert_eq!(frames.speed_ms, 100);
 /// ```
 #[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Frames {
     /// the sequence of frames to be displayed
     pub frames: Vec<StyledString>,
@@ -44,6 +51,13 @@ pub struct Frames {
     pub text: StyledString,
     /// if the animation is active
     pub stop: bool,
+    /// drawn in front of the text
+    pub prefix: Option<StyledString>,
+    /// drawn behind the text
+    pub suffix: Option<StyledString>,
+    /// shows the time since the spinner was started (e.g. `12.3s`) behind the text,
+    /// the timer freezes once the spinner is stopped
+    pub elapsed: bool,
 }
 
 impl Default for Frames {
@@ -101,6 +115,9 @@ impl Frames {
                 string: "".to_string(),
                 style: Default::default(),
             },
+            prefix: None,
+            suffix: None,
+            elapsed: false,
         }
     }
 
@@ -118,6 +135,59 @@ impl Frames {
         self.stop = true;
     }
 
+    /// sets a prefix drawn in front of the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::Frames;
+    /// use zenity::style::{Color, StyledString};
+    ///
+    /// let frames = Frames::default().set_prefix(StyledString::simple("[build]", Some(Color::Blue), None, None));
+    /// # assert_eq!(frames.prefix.unwrap().string, "[build]");
+    /// ```
+    pub fn set_prefix(&mut self, prefix: StyledString) -> Self {
+        self.prefix = Some(prefix);
+
+        self.clone()
+    }
+
+    /// sets a suffix drawn behind the text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::Frames;
+    /// use zenity::style::StyledString;
+    ///
+    /// let frames = Frames::default().set_suffix(StyledString::new("(3 files)"));
+    /// # assert_eq!(frames.suffix.unwrap().string, "(3 files)");
+    /// ```
+    pub fn set_suffix(&mut self, suffix: StyledString) -> Self {
+        self.suffix = Some(suffix);
+
+        self.clone()
+    }
+
+    /// shows the elapsed time behind the text
+    ///
+    /// the timer starts when the spinner is added (or when `run_all` is called)
+    /// and freezes once the spinner is stopped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner};
+    ///
+    /// let spinner = MultiSpinner::new();
+    /// spinner.add(Frames::default().show_elapsed());
+    /// ```
+    pub fn show_elapsed(&mut self) -> Self {
+        self.elapsed = true;
+
+        self.clone()
+    }
+
     /// ⠋
     /// ⠹
     /// ⠧
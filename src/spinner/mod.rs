@@ -20,18 +20,33 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use std::thread;
 use std::time::Duration;
 
+pub use crate::sort::SortBy;
+pub use counter::StepCounter;
 pub use frames::*;
+pub use task::Status;
 
 use crate::iterators::balanced_iterator;
-use crate::style;
-use crate::style::{Attribute, Color, ContentStyle, StyledString};
-use crate::terminal::console_render::{get_rows, push_content_up};
+use crate::style::{Attributes, Color, ContentStyle, StyledString};
+use crate::terminal::console_render::{
+    get_rows, push_content_up, LiveRegion, Pause, RenderLoop, SYNCHRONIZED_OUTPUT,
+};
 use crate::terminal::{console_cursor, console_render};
+use task::{Task, Tasks};
 
+mod counter;
 pub mod frames;
+mod registry;
+mod task;
+
+/// time between two rendered frames
+const FRAME_INTERVAL: Duration = Duration::from_millis(80);
+
+/// drawn in place of the frame of a succeeded spinner
+const SUCCEEDED_SYMBOL: &str = "✔";
+/// drawn in place of the frame of a failed spinner
+const FAILED_SYMBOL: &str = "✖";
 
 /// struct holding multiple spinners
 ///
@@ -56,10 +71,13 @@ pub mod frames;
 /// ```
 #[derive(Clone)]
 pub struct MultiSpinner {
-    spinner: Arc<Mutex<HashMap<usize, Frames>>>,
-    show_line_number: Arc<Mutex<bool>>,
+    spinner: Arc<Mutex<Tasks>>,
+    step_counter: Arc<Mutex<Option<StepCounter>>>,
+    synchronized_output: Arc<Mutex<bool>>,
+    sort: Arc<Mutex<SortBy>>,
     clear_type: Arc<Mutex<Option<u16>>>,
-    stop: Arc<Mutex<bool>>,
+    pause: Pause,
+    render_loop: RenderLoop,
 }
 
 impl Default for MultiSpinner {
@@ -108,9 +126,12 @@ impl MultiSpinner {
     /// ```
     pub fn new() -> Self {
         MultiSpinner {
-            spinner: Arc::new(Mutex::new(HashMap::new())),
-            stop: Arc::new(Mutex::new(false)),
-            show_line_number: Arc::new(Mutex::new(false)),
+            spinner: Arc::new(Mutex::new(Tasks::new())),
+            pause: Pause::default(),
+            render_loop: RenderLoop::default(),
+            step_counter: Arc::new(Mutex::new(None)),
+            synchronized_output: Arc::new(Mutex::new(*SYNCHRONIZED_OUTPUT)),
+            sort: Arc::new(Mutex::new(SortBy::default())),
             clear_type: Arc::new(Mutex::new(Some(get_rows()))),
         }
     }
@@ -131,12 +152,39 @@ impl MultiSpinner {
     /// spinner.add(Frames::aesthetic_load());
     /// ```
     pub fn add(&self, frames: Frames) -> usize {
-        let mut spinner_map = self.spinner.lock().unwrap();
-        let uid = spinner_map.len() + 1;
+        self.spinner.lock().unwrap().insert(Task::new(frames, None))
+    }
 
-        spinner_map.insert(uid, frames);
+    /// create a new spinner nested below another spinner
+    ///
+    /// children are drawn indented below their parent and are collapsed
+    /// once the parent is stopped, if the parent uid is invalid the spinner is added at the top level
+    ///
+    /// # Returns
+    ///
+    /// unique identifier
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner, Status};
+    ///
+    /// let spinner = MultiSpinner::new();
+    ///
+    /// let build = spinner.add(Frames::default());
+    /// let compile = spinner.add_child(&build, Frames::default());
+    /// let link = spinner.add_child(&build, Frames::default());
+    ///
+    /// spinner.succeed(&compile);
+    /// spinner.fail(&link);
+    ///
+    /// // the parent fails if any of its children failed
+    /// assert_eq!(spinner.status(&build), Some(Status::Failed));
+    /// ```
+    pub fn add_child(&self, parent: &usize, frames: Frames) -> usize {
+        let mut spinner_map = self.spinner.lock().unwrap();
+        let parent = spinner_map.contains_key(parent).then_some(*parent);
 
-        uid
+        spinner_map.insert(Task::new(frames, parent))
     }
 
     /// get the last create uid
@@ -157,15 +205,81 @@ impl MultiSpinner {
     /// let spinner1_uid = spinner.get_last();
     /// ```
     pub fn get_last(&self) -> usize {
-        let spinner_map = self.spinner.lock().unwrap();
+        // uids are incremental, so the number of spinners is the last uid (or 0 if empty)
+        self.spinner.lock().unwrap().len()
+    }
 
-        // Check if the spinner is empty
-        if spinner_map.is_empty() {
-            return 0;
-        }
+    /// create a new spinner right before another spinner
+    ///
+    /// the new spinner gets the same parent as `uid`,
+    /// if the uid is invalid the spinner is added at the end
+    ///
+    /// # Returns
+    ///
+    /// unique identifier
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner};
+    ///
+    /// let spinner = MultiSpinner::new();
+    ///
+    /// let test = spinner.add(Frames::default());
+    /// // drawn above `test`
+    /// let build = spinner.insert_before(&test, Frames::default());
+    /// ```
+    pub fn insert_before(&self, uid: &usize, frames: Frames) -> usize {
+        self.spinner
+            .lock()
+            .unwrap()
+            .insert_next_to(*uid, false, Task::new(frames, None))
+    }
 
-        // Get the maximum key value (uid) from the spinner map
-        spinner_map.keys().copied().max().unwrap()
+    /// create a new spinner right after another spinner
+    ///
+    /// the new spinner gets the same parent as `uid`,
+    /// if the uid is invalid the spinner is added at the end
+    ///
+    /// # Returns
+    ///
+    /// unique identifier
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner};
+    ///
+    /// let spinner = MultiSpinner::new();
+    ///
+    /// let build = spinner.add(Frames::default());
+    /// let deploy = spinner.add(Frames::default());
+    /// // drawn between `build` and `deploy`
+    /// let test = spinner.insert_after(&build, Frames::default());
+    /// ```
+    pub fn insert_after(&self, uid: &usize, frames: Frames) -> usize {
+        self.spinner
+            .lock()
+            .unwrap()
+            .insert_next_to(*uid, true, Task::new(frames, None))
+    }
+
+    /// sets the order the spinners are drawn in
+    ///
+    /// the order is applied on every frame, so e.g. with [`SortBy::RunningFirst`]
+    /// spinners sink to the bottom as soon as they finish
+    ///
+    /// spinners have no progress, [`SortBy::LeastProgress`] and [`SortBy::MostProgress`]
+    /// keep the insertion order
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::{MultiSpinner, SortBy};
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.sort_by(SortBy::Name);
+    /// ```
+    pub fn sort_by(&self, sort: SortBy) {
+        *self.sort.lock().unwrap() = sort;
     }
 
     /// Sets the number of rows to clear in the terminal before starting the animation.
@@ -193,7 +307,7 @@ impl MultiSpinner {
     ///
     /// # Parameters
     /// - `rows`: An optional `u16` specifying the number of rows to clear.
-    /// If `None`, no rows will be cleared.
+    ///   If `None`, no rows will be cleared.
     ///
     pub fn clear(&self, rows: Option<u16>) {
         *self.clear_type.lock().unwrap() = rows;
@@ -214,9 +328,7 @@ impl MultiSpinner {
     /// spinner.set_text(&spinner.get_last(),"example".to_string());
     /// ```
     pub fn set_text(&self, uid: &usize, new_text: String) {
-        if let Some(spinner) = self.spinner.lock().unwrap().get_mut(uid) {
-            spinner.text = StyledString::new(&new_text);
-        }
+        self.set_styled_text(uid, StyledString::new(&new_text));
     }
 
     /// set a styled text of a specific spinner
@@ -236,8 +348,92 @@ impl MultiSpinner {
     ///     StyledString::simple("test string", Some(Color::Red), Some(Color::Black), None));
     /// ```
     pub fn set_styled_text(&self, uid: &usize, new_text: StyledString) {
-        if let Some(spinner) = self.spinner.lock().unwrap().get_mut(uid) {
-            spinner.text = new_text;
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(uid) {
+            task.frames.text = new_text;
+        }
+    }
+
+    /// set the prefix of a specific spinner, drawn in front of the text
+    ///
+    /// if the uid is invalid, this does nothing
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    /// use zenity::style::{Color, StyledString};
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.set_prefix(&spinner.get_last(), StyledString::simple("[1/3]", Some(Color::Grey), None, None));
+    /// ```
+    pub fn set_prefix(&self, uid: &usize, prefix: StyledString) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(uid) {
+            task.frames.prefix = Some(prefix);
+        }
+    }
+
+    /// set the suffix of a specific spinner, drawn behind the text
+    ///
+    /// if the uid is invalid, this does nothing
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    /// use zenity::style::StyledString;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.set_suffix(&spinner.get_last(), StyledString::new("(12 MB)"));
+    /// ```
+    pub fn set_suffix(&self, uid: &usize, suffix: StyledString) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(uid) {
+            task.frames.suffix = Some(suffix);
+        }
+    }
+
+    /// returns how long a spinner has been running, or ran until it was stopped
+    ///
+    /// the timer starts when the spinner is added or when `run_all` is called
+    ///
+    /// # Returns
+    ///
+    /// `None` if the uid is invalid
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    /// let uid = spinner.get_last();
+    ///
+    /// spinner.stop(&uid);
+    ///
+    /// // frozen once stopped
+    /// assert_eq!(spinner.elapsed(&uid), spinner.elapsed(&uid));
+    /// ```
+    pub fn elapsed(&self, uid: &usize) -> Option<Duration> {
+        self.spinner.lock().unwrap().get(uid).map(Task::elapsed)
+    }
+
+    /// returns a cheap handle to a spinner
+    ///
+    /// the handle can be cloned and moved into other threads or tasks,
+    /// if the uid is invalid calls on the handle do nothing
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// let handle = spinner.handle(&spinner.get_last());
+    /// handle.set_text("Loading...".to_string());
+    /// ```
+    pub fn handle(&self, uid: &usize) -> SpinnerHandle {
+        SpinnerHandle {
+            spinner: Arc::clone(&self.spinner),
+            uid: *uid,
         }
     }
 
@@ -254,15 +450,79 @@ impl MultiSpinner {
     /// spinner.stop(&spinner.get_last());
     /// ```
     pub fn stop(&self, uid: &usize) {
-        if let Some(spinner) = self.spinner.lock().unwrap().get_mut(uid) {
-            spinner.stop = true; // Set spinner.stop to true
+        self.finish(uid, Status::Stopped);
+    }
+
+    /// stops a spinner and marks it as succeeded with a green check mark
+    ///
+    /// if the uid is invalid this does nothing
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.succeed(&spinner.get_last());
+    /// ```
+    pub fn succeed(&self, uid: &usize) {
+        self.finish(uid, Status::Succeeded);
+    }
+
+    /// stops a spinner and marks it as failed with a red cross
+    ///
+    /// the parents of the spinner are shown as failed as well,
+    /// if the uid is invalid this does nothing
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.fail(&spinner.get_last());
+    /// ```
+    pub fn fail(&self, uid: &usize) {
+        self.finish(uid, Status::Failed);
+    }
+
+    /// returns the status of a spinner
+    ///
+    /// a spinner is reported as failed if any of its (nested) children failed
+    ///
+    /// # Returns
+    ///
+    /// `None` if the uid is invalid
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::{MultiSpinner, Status};
+    ///
+    /// let spinner = MultiSpinner::default();
+    /// let uid = spinner.get_last();
+    ///
+    /// spinner.stop(&uid);
+    /// assert_eq!(spinner.status(&uid), Some(Status::Stopped));
+    /// ```
+    pub fn status(&self, uid: &usize) -> Option<Status> {
+        task::aggregate_status(&self.spinner.lock().unwrap(), *uid)
+    }
+
+    fn finish(&self, uid: &usize, status: Status) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(uid) {
+            task.finish(status);
         }
     }
 
-    /// shows the line number of the running spinners
+    /// shows a step counter in front of the top level spinners
     ///
     /// [1/4]  .¸¸¸¸¸¸¸¸
     ///
+    /// uses the default [`StepCounter`], see [`MultiSpinner::step_counter`] to configure it
+    ///
     /// ## Example
     ///
     /// ```
@@ -274,7 +534,99 @@ impl MultiSpinner {
     /// spinner.show_line_number();
     /// ```
     pub fn show_line_number(&self) {
-        *self.show_line_number.lock().unwrap() = true;
+        self.step_counter(StepCounter::default());
+    }
+
+    /// shows a step counter in front of the top level spinners
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner, StepCounter};
+    ///
+    /// let spinner = MultiSpinner::new();
+    /// spinner.add(Frames::default());
+    ///
+    /// // step x of 7, counting the finished steps
+    /// spinner.step_counter(StepCounter::new().set_total(7).count_finished());
+    /// ```
+    pub fn step_counter(&self, counter: StepCounter) {
+        *self.step_counter.lock().unwrap() = Some(counter);
+    }
+
+    /// enables or disables synchronized output (DEC mode 2026)
+    ///
+    /// when enabled each frame is wrapped in begin/end synchronized update sequences,
+    /// so terminals supporting it (kitty, WezTerm, foot, ...) update all lines at once
+    ///
+    /// by default this is enabled if the terminal is detected as supporting it,
+    /// the detection can also be turned off by setting `ZENITY_SYNC_OUTPUT=0`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.synchronized_output(false);
+    /// ```
+    pub fn synchronized_output(&self, enable: bool) {
+        *self.synchronized_output.lock().unwrap() = enable;
+    }
+
+    /// hides the spinners and stops drawing them until [`MultiSpinner::resume`] is called
+    ///
+    /// waits for the frame currently being drawn, so the terminal can be written to
+    /// right after this returns, see [`MultiSpinner::suspend`] for a scoped version
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// spinner.pause();
+    /// println!("the spinners are hidden");
+    /// spinner.resume();
+    /// ```
+    pub fn pause(&self) {
+        self.pause.pause();
+    }
+
+    /// continues drawing the spinners after [`MultiSpinner::pause`]
+    ///
+    /// the spinners are drawn below the output written while paused,
+    /// so the output should end with a newline
+    pub fn resume(&self) {
+        self.pause.resume();
+    }
+
+    /// hides the spinners while running `f` and redraws them afterwards
+    ///
+    /// the spinners are redrawn even if `f` panics
+    ///
+    /// useful to print output, ask the user (e.g. with `menu::input::Confirm`)
+    /// or run a child process in the middle of an operation
+    ///
+    /// # Returns
+    ///
+    /// the value returned by `f`
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    ///
+    /// let answer = spinner.suspend(|| {
+    ///     println!("the spinners are hidden");
+    ///     42
+    /// });
+    /// # assert_eq!(answer, 42);
+    /// ```
+    pub fn suspend<T>(&self, f: impl FnOnce() -> T) -> T {
+        self.pause.suspend(f)
     }
 
     /// execute all created spinners
@@ -299,75 +651,491 @@ impl MultiSpinner {
             push_content_up(rows);
         }
 
-        let spinners = Arc::clone(&self.spinner);
-        let stop = Arc::clone(&self.stop);
-        let show_line_number = Arc::clone(&self.show_line_number);
+        self.start_timers();
+        console_cursor::save_hide_cursor();
 
-        thread::spawn(move || {
-            console_cursor::save_hide_cursor();
+        let mut renderer = self.renderer();
+        self.render_loop
+            .spawn_thread(FRAME_INTERVAL, move || renderer.render());
+    }
 
-            let mut index = 1_u16;
-            let mut max_line_number: usize = 0;
+    /// execute all created spinners on the current tokio runtime
+    ///
+    /// works like [`MultiSpinner::run_all`] but the spinners are drawn by a runtime task
+    /// instead of a dedicated thread
+    ///
+    /// dropping the spinner stops the task without waiting for it,
+    /// the returned handle finishes once the spinners are cleared and the cursor is restored
+    ///
+    /// # Panics
+    ///
+    /// if called outside of a tokio runtime, like [`tokio::spawn`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let spinner = MultiSpinner::new();
+    /// let uid = spinner.add(Default::default());
+    ///
+    /// spinner.run_async();
+    ///
+    /// let result: Result<u8, String> = spinner.handle(&uid).wrap(async { Ok(42) }).await;
+    /// # assert_eq!(result, Ok(42));
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn run_async(&self) -> tokio::task::JoinHandle<()> {
+        // panic before touching the terminal
+        let runtime = tokio::runtime::Handle::current();
 
-            while !*stop.lock().unwrap() {
-                // collect frames and texts from all spinners
-                for (line_number, spinner) in spinners.lock().unwrap().iter() {
-                    let mut combined_vec = Vec::new();
+        if let Some(rows) = *self.clear_type.lock().unwrap() {
+            push_content_up(rows);
+        }
+
+        self.start_timers();
+        console_cursor::save_hide_cursor();
+
+        let mut renderer = self.renderer();
+        self.render_loop
+            .spawn_task(&runtime, FRAME_INTERVAL, move || renderer.render())
+    }
 
-                    if *show_line_number.lock().unwrap() {
-                        // update the max_line_number if a larger line number is encountered
-                        if *line_number > max_line_number {
-                            max_line_number = *line_number;
-                        }
+    /// restarts the elapsed timers of all running spinners
+    fn start_timers(&self) {
+        self.spinner
+            .lock()
+            .unwrap()
+            .values_mut()
+            .for_each(Task::restart_timer);
+    }
+
+    fn renderer(&self) -> SpinnerRenderer {
+        SpinnerRenderer {
+            spinners: Arc::clone(&self.spinner),
+            step_counter: Arc::clone(&self.step_counter),
+            synchronized_output: Arc::clone(&self.synchronized_output),
+            sort: Arc::clone(&self.sort),
+            index: 1,
+            pause: self.pause.clone(),
+            region: LiveRegion::new(),
+        }
+    }
+}
 
-                        console_render::push_styled_string!(
+/// state of the render loop, shared by [`MultiSpinner::run_all`] and `MultiSpinner::run_async`
+struct SpinnerRenderer {
+    spinners: Arc<Mutex<Tasks>>,
+    step_counter: Arc<Mutex<Option<StepCounter>>>,
+    synchronized_output: Arc<Mutex<bool>>,
+    sort: Arc<Mutex<SortBy>>,
+    index: usize,
+    pause: Pause,
+    region: LiveRegion,
+}
+
+impl SpinnerRenderer {
+    /// draws the next frame of all spinners
+    fn render(&mut self) {
+        // held while drawing, so pausing waits for this frame
+        let pause = self.pause.clone();
+        let mut pause_state = pause.lock();
+        if pause_state.paused {
+            return;
+        }
+        if std::mem::take(&mut pause_state.resumed) {
+            self.region.reanchor();
+        }
+
+        self.region.update_size();
+        self.region
+            .set_synchronized(*self.synchronized_output.lock().unwrap());
+
+        let mut lines = Vec::new();
+
+        // collect frames and texts from all spinners
+        let sort = *self.sort.lock().unwrap();
+        let step_counter = self.step_counter.lock().unwrap().clone();
+        let spinner_map = self.spinners.lock().unwrap();
+
+        let tree_lines = task::tree_lines(&spinner_map, sort);
+        let statuses = task::aggregate_statuses(&spinner_map);
+        let counters = step_counter
+            .map(|counter| step_counters(&counter, &spinner_map, &tree_lines))
+            .unwrap_or_default();
+        let counter_width = counters.values().map(StyledString::width).max();
+
+        for (line_number, guides) in tree_lines {
+            let Some(task) = spinner_map.get(&line_number) else {
+                continue;
+            };
+            let spinner = &task.frames;
+            let mut combined_vec = Vec::new();
+
+            if let Some(counter_width) = counter_width {
+                // children are aligned below the counter of their parent
+                match counters.get(&line_number) {
+                    Some(counter) => {
+                        console_render::push_unstyled_spaces!(
                             combined_vec,
-                            format!("[{}/{}]", &line_number, &max_line_number),
-                            Some(Color::Grey),
-                            None,
-                            None,
-                            style::combine_attributes(&[&Attribute::Italic])
+                            counter_width - counter.width()
                         );
-
-                        // to prevent style to apply to the spacing
-                        console_render::push_unstyled_spaces!(combined_vec, 1);
+                        combined_vec.push(counter.clone());
                     }
+                    None => console_render::push_unstyled_spaces!(combined_vec, counter_width),
+                }
 
-                    // if the spinner is not stopped, include new frames and update text
-                    if !spinner.stop {
-                        let frames = vec![spinner.frames.clone()];
+                // to prevent style to apply to the spacing
+                console_render::push_unstyled_spaces!(combined_vec, 1);
+            }
 
-                        let current_frame = balanced_iterator(index as usize, &frames)
-                            .iter()
-                            .map(|opt| opt.cloned().unwrap_or_default())
-                            .collect::<Vec<_>>();
+            if !guides.is_empty() {
+                console_render::push_styled_string!(
+                    combined_vec,
+                    guides,
+                    Some(Color::DarkGrey),
+                    None,
+                    None,
+                    Attributes::default()
+                );
+            }
 
-                        if let Some(first_frame) = current_frame.first() {
-                            combined_vec.push(first_frame.clone());
-                        }
-                        console_render::push_unstyled_spaces!(combined_vec, 1);
-                    }
+            // finished spinners show their result instead of a frame
+            match statuses.get(&line_number).copied() {
+                Some(Status::Succeeded) => {
+                    combined_vec.push(StyledString::simple(
+                        SUCCEEDED_SYMBOL,
+                        Some(Color::Green),
+                        None,
+                        None,
+                    ));
+                    console_render::push_unstyled_spaces!(combined_vec, 1);
+                }
+                Some(Status::Failed) if spinner.stop => {
+                    combined_vec.push(StyledString::simple(
+                        FAILED_SYMBOL,
+                        Some(Color::Red),
+                        None,
+                        None,
+                    ));
+                    console_render::push_unstyled_spaces!(combined_vec, 1);
+                }
+                _ => {}
+            }
 
-                    // always include spinner text
-                    combined_vec.push(spinner.text.clone());
+            // if the spinner is not stopped, include new frames and update text
+            if !spinner.stop {
+                let frames = vec![spinner.frames.clone()];
 
-                    console_render::render_styled_line(*line_number as u16, &combined_vec);
+                let current_frame = balanced_iterator(self.index, &frames)
+                    .iter()
+                    .map(|opt| opt.cloned().unwrap_or_default())
+                    .collect::<Vec<_>>();
+
+                if let Some(first_frame) = current_frame.first() {
+                    combined_vec.push(first_frame.clone());
                 }
+                console_render::push_unstyled_spaces!(combined_vec, 1);
+            }
+
+            if let Some(prefix) = &spinner.prefix {
+                combined_vec.push(prefix.clone());
+                console_render::push_unstyled_spaces!(combined_vec, 1);
+            }
+
+            // always include spinner text
+            combined_vec.push(spinner.text.clone());
 
-                index += 1;
+            if let Some(suffix) = &spinner.suffix {
+                console_render::push_unstyled_spaces!(combined_vec, 1);
+                combined_vec.push(suffix.clone());
+            }
 
-                thread::sleep(Duration::from_millis(80));
+            if spinner.elapsed {
+                console_render::push_unstyled_spaces!(combined_vec, 1);
+                combined_vec.push(StyledString::simple(
+                    &task::format_elapsed(task.elapsed()),
+                    Some(Color::DarkGrey),
+                    None,
+                    None,
+                ));
             }
-        });
+
+            lines.push(combined_vec);
+        }
+        drop(spinner_map);
+
+        self.region.render(lines);
+
+        self.index += 1;
     }
 }
 
-impl Drop for MultiSpinner {
-    /// stops the loading animation thread when the `LoadingAnimation` object is dropped
+impl Drop for SpinnerRenderer {
+    /// clears the spinners when the render loop ends
     fn drop(&mut self) {
-        *self.stop.lock().unwrap() = true;
-
         console_render::cleanup();
         console_cursor::reset_cursor();
     }
 }
+
+/// renders the step counter of each top level spinner
+fn step_counters(
+    counter: &StepCounter,
+    tasks: &Tasks,
+    tree_lines: &[(usize, String)],
+) -> HashMap<usize, StyledString> {
+    let roots = tree_lines
+        .iter()
+        .filter_map(|(uid, _)| {
+            tasks
+                .get(uid)
+                .filter(|task| task.parent.is_none())
+                .map(|task| (*uid, task))
+        })
+        .collect::<Vec<_>>();
+
+    let finished = roots
+        .iter()
+        .map(|(_, task)| task.finished_at())
+        .collect::<Vec<_>>();
+
+    roots
+        .iter()
+        .zip(counter.steps(&finished))
+        .map(|((uid, _), step)| (*uid, counter.render(step, roots.len())))
+        .collect()
+}
+
+/// cheap handle to a single spinner of a [`MultiSpinner`]
+///
+/// handles are `Send + Sync` and can be cloned and moved into other threads or tasks,
+/// dropping a handle doesn't affect the spinner
+///
+/// # Example
+///
+/// ```
+/// use std::thread;
+/// use zenity::spinner::MultiSpinner;
+///
+/// let spinner = MultiSpinner::default();
+/// let handle = spinner.handle(&spinner.get_last());
+///
+/// thread::spawn(move || {
+///     handle.set_text("done".to_string());
+///     handle.stop();
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct SpinnerHandle {
+    spinner: Arc<Mutex<Tasks>>,
+    uid: usize,
+}
+
+impl SpinnerHandle {
+    /// unique identifier of the spinner
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// let spinner = MultiSpinner::default();
+    /// let handle = spinner.handle(&spinner.get_last());
+    ///
+    /// assert_eq!(handle.uid(), spinner.get_last());
+    /// ```
+    pub fn uid(&self) -> usize {
+        self.uid
+    }
+
+    /// set the text of the spinner
+    ///
+    /// see [`MultiSpinner::set_text`]
+    pub fn set_text(&self, new_text: String) {
+        self.set_styled_text(StyledString::new(&new_text));
+    }
+
+    /// set a styled text of the spinner
+    ///
+    /// see [`MultiSpinner::set_styled_text`]
+    pub fn set_styled_text(&self, new_text: StyledString) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(&self.uid) {
+            task.frames.text = new_text;
+        }
+    }
+
+    /// set the prefix of the spinner
+    ///
+    /// see [`MultiSpinner::set_prefix`]
+    pub fn set_prefix(&self, prefix: StyledString) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(&self.uid) {
+            task.frames.prefix = Some(prefix);
+        }
+    }
+
+    /// set the suffix of the spinner
+    ///
+    /// see [`MultiSpinner::set_suffix`]
+    pub fn set_suffix(&self, suffix: StyledString) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(&self.uid) {
+            task.frames.suffix = Some(suffix);
+        }
+    }
+
+    /// returns how long the spinner has been running
+    ///
+    /// see [`MultiSpinner::elapsed`]
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.spinner
+            .lock()
+            .unwrap()
+            .get(&self.uid)
+            .map(Task::elapsed)
+    }
+
+    /// create a new spinner nested below this spinner
+    ///
+    /// see [`MultiSpinner::add_child`]
+    ///
+    /// # Returns
+    ///
+    /// handle to the new spinner
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner};
+    ///
+    /// let spinner = MultiSpinner::default();
+    /// let parent = spinner.handle(&spinner.get_last());
+    ///
+    /// let child = parent.add_child(Frames::default());
+    /// child.set_text("step 1".to_string());
+    /// ```
+    pub fn add_child(&self, frames: Frames) -> SpinnerHandle {
+        let mut spinner_map = self.spinner.lock().unwrap();
+        let parent = spinner_map.contains_key(&self.uid).then_some(self.uid);
+        let uid = spinner_map.insert(Task::new(frames, parent));
+
+        SpinnerHandle {
+            spinner: Arc::clone(&self.spinner),
+            uid,
+        }
+    }
+
+    /// stops the spinner
+    ///
+    /// see [`MultiSpinner::stop`]
+    pub fn stop(&self) {
+        self.finish(Status::Stopped);
+    }
+
+    /// stops the spinner and marks it as succeeded
+    ///
+    /// see [`MultiSpinner::succeed`]
+    pub fn succeed(&self) {
+        self.finish(Status::Succeeded);
+    }
+
+    /// stops the spinner and marks it as failed
+    ///
+    /// see [`MultiSpinner::fail`]
+    pub fn fail(&self) {
+        self.finish(Status::Failed);
+    }
+
+    /// returns the status of the spinner
+    ///
+    /// see [`MultiSpinner::status`]
+    pub fn status(&self) -> Option<Status> {
+        task::aggregate_status(&self.spinner.lock().unwrap(), self.uid)
+    }
+
+    fn finish(&self, status: Status) {
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(&self.uid) {
+            task.finish(status);
+        }
+    }
+
+    /// shows the spinner for the lifetime of a future
+    ///
+    /// once the future completes the spinner is marked as succeeded and its text is colored green,
+    /// if the future returned an error it is marked as failed and the text is replaced with the error in red
+    ///
+    /// the spinner is also stopped if the future gets cancelled (dropped before completion)
+    ///
+    /// ## Example
+    /// ```
+    /// use zenity::spinner::MultiSpinner;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let spinner = MultiSpinner::new();
+    /// let handle = spinner.handle(&spinner.add(Default::default()));
+    /// handle.set_text("Downloading...".to_string());
+    ///
+    /// spinner.run_async();
+    ///
+    /// let result = handle
+    ///     .wrap(async { Err::<(), _>("Download failed".to_string()) })
+    ///     .await;
+    /// # assert!(result.is_err());
+    /// # assert_eq!(handle.status(), Some(zenity::spinner::Status::Failed));
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn wrap<F, T, E>(&self, future: F) -> Result<T, E>
+    where
+        F: std::future::Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        // stops the spinner even if the future gets dropped while awaiting it
+        struct StopGuard<'a>(&'a SpinnerHandle);
+
+        impl Drop for StopGuard<'_> {
+            fn drop(&mut self) {
+                self.0.stop();
+            }
+        }
+
+        let guard = StopGuard(self);
+        let result = future.await;
+
+        // the status is set below, so the guard must not overwrite it
+        std::mem::forget(guard);
+
+        if let Some(task) = self.spinner.lock().unwrap().get_mut(&self.uid) {
+            match &result {
+                Ok(_) => {
+                    task.frames.text.style.foreground_color = Some(Color::Green);
+                    task.finish(Status::Succeeded);
+                }
+                Err(err) => {
+                    task.frames.text =
+                        StyledString::simple(&err.to_string(), Some(Color::Red), None, None);
+                    task.finish(Status::Failed);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Drop for MultiSpinner {
+    /// stops the loading animation thread when the `LoadingAnimation` object is dropped
+    ///
+    /// waits for the frame being drawn by [`MultiSpinner::run_all`],
+    /// see [`MultiSpinner::run_async`] for the runtime task
+    fn drop(&mut self) {
+        // a running loop clears the spinners after its last frame
+        if !self.render_loop.stop() {
+            console_render::cleanup();
+            console_cursor::reset_cursor();
+        }
+    }
+}
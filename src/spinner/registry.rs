@@ -0,0 +1,285 @@
+//! lookup of spinner animations by name
+//!
+//! ```
+//! use zenity::spinner::Frames;
+//!
+//! // e.g. from a config value `spinner = "moon"`
+//! let frames = Frames::by_name("moon").unwrap_or_default();
+//! # assert_eq!(frames.frames, Frames::moon().frames);
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use crate::spinner::Frames;
+
+/// constructor of a predefined animation
+type Constructor = fn() -> Frames;
+
+/// the predefined animations by the name of their constructor
+const PREDEFINED: &[(&str, Constructor)] = &[
+    ("dot_spinner1", Frames::dot_spinner1),
+    ("dot_spinner2", Frames::dot_spinner2),
+    ("dot_spinner3", Frames::dot_spinner3),
+    ("dot_spinner4", Frames::dot_spinner4),
+    ("dot_spinner5", Frames::dot_spinner5),
+    ("dot_spinner6", Frames::dot_spinner6),
+    ("dot_spinner7", Frames::dot_spinner7),
+    ("dot_spinner8", Frames::dot_spinner8),
+    ("dot_spinner9", Frames::dot_spinner9),
+    ("dot_spinner10", Frames::dot_spinner10),
+    ("dot_spinner11", Frames::dot_spinner11),
+    ("kaomoji", Frames::kaomoji),
+    ("aesthetic_spin", Frames::aesthetic_spin),
+    ("aesthetic_load", Frames::aesthetic_load),
+    ("clock", Frames::clock),
+    ("small_bouncing_bar", Frames::small_bouncing_bar),
+    ("small_loading_bar", Frames::small_loading_bar),
+    ("loading_bar_with_arrow", Frames::loading_bar_with_arrow),
+    (
+        "short_loading_bar_with_arrow",
+        Frames::short_loading_bar_with_arrow,
+    ),
+    ("material", Frames::material),
+    ("moon", Frames::moon),
+    ("dots_simple1", Frames::dots_simple1),
+    ("dots_simple2", Frames::dots_simple2),
+    ("japanese", Frames::japanese),
+    ("line", Frames::line),
+    ("line2", Frames::line2),
+    ("block", Frames::block),
+    ("block_spinn", Frames::block_spinn),
+    ("arrow_spinn", Frames::arrow_spinn),
+    ("big_arrow_spinn", Frames::big_arrow_spinn),
+    ("line_spinner", Frames::line_spinner),
+    ("line_spinner_simple", Frames::line_spinner_simple),
+    ("corner", Frames::corner),
+    ("abc", Frames::abc),
+    ("earth", Frames::earth),
+    ("arrow_row", Frames::arrow_row),
+    ("fractions", Frames::fractions),
+    ("star1", Frames::star1),
+    ("star2", Frames::star2),
+    ("dot_bounce", Frames::dot_bounce),
+    ("flip", Frames::flip),
+    ("binary", Frames::binary),
+    ("big_loading_bar", Frames::big_loading_bar),
+    ("wall_bounce", Frames::wall_bounce),
+    ("wall_bounce_line", Frames::wall_bounce_line),
+    ("stack", Frames::stack),
+    ("toggle", Frames::toggle),
+    ("toggle2", Frames::toggle2),
+    ("toggle3", Frames::toggle3),
+    ("toggle4", Frames::toggle4),
+    ("toggle5", Frames::toggle5),
+    ("toggle6", Frames::toggle6),
+    ("toggle7", Frames::toggle7),
+    ("toggle8", Frames::toggle8),
+    ("toggle9", Frames::toggle9),
+    ("toggle10", Frames::toggle10),
+    ("toggle11", Frames::toggle11),
+    ("toggle12", Frames::toggle12),
+    ("toggle13", Frames::toggle13),
+    ("arc", Frames::arc),
+    ("circle", Frames::circle),
+    ("square_corners", Frames::square_corners),
+    ("circle_corners", Frames::circle_corners),
+    ("circle_halves", Frames::circle_halves),
+    ("bouncing_ball", Frames::bouncing_ball),
+    ("smiley", Frames::smiley),
+    ("monkey", Frames::monkey),
+    ("hearts", Frames::hearts),
+    ("runner", Frames::runner),
+    ("raining", Frames::raining),
+    ("weather", Frames::weather),
+    ("christmas_tree", Frames::christmas_tree),
+    ("nade", Frames::nade),
+    ("dots_simple_big1", Frames::dots_simple_big1),
+    ("dots_simple_big2", Frames::dots_simple_big2),
+    ("dots_simple_big3", Frames::dots_simple_big3),
+    ("dots_simple_big4", Frames::dots_simple_big4),
+    ("fist_bump", Frames::fist_bump),
+    ("finger_dance", Frames::finger_dance),
+    ("mind_blown", Frames::mind_blown),
+    ("speaker", Frames::speaker),
+    ("arrows", Frames::arrows),
+    ("dot_box", Frames::dot_box),
+    ("simple_line_spin", Frames::simple_line_spin),
+    ("bomb", Frames::bomb),
+    ("dot_bounce2", Frames::dot_bounce2),
+    ("orange_pulse", Frames::orange_pulse),
+    ("blue_pulse", Frames::blue_pulse),
+    ("green_pulse", Frames::green_pulse),
+    ("red_pulse", Frames::red_pulse),
+    ("other", Frames::other),
+    ("pray", Frames::pray),
+    ("wavy", Frames::wavy),
+    ("wavy2", Frames::wavy2),
+    ("wavy3", Frames::wavy3),
+    ("wavy4", Frames::wavy4),
+    ("soccer", Frames::soccer),
+    ("layer", Frames::layer),
+    ("matrix_glitch", Frames::matrix_glitch),
+    ("matrix_glitch2", Frames::matrix_glitch2),
+    ("matrix_glitch2_small", Frames::matrix_glitch2_small),
+    ("dwarf_fortress", Frames::dwarf_fortress),
+];
+
+lazy_static! {
+    /// animations registered at runtime with [`Frames::register`]
+    static ref REGISTERED: RwLock<BTreeMap<String, Frames>> = RwLock::new(BTreeMap::new());
+}
+
+impl Frames {
+    /// returns the animation with the given name
+    ///
+    /// the names of the predefined animations are the names of their constructors (e.g. `"moon"`),
+    /// animations registered with [`Frames::register`] take precedence
+    ///
+    /// # Returns
+    ///
+    /// `None` if no animation has this name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::Frames;
+    ///
+    /// assert!(Frames::by_name("dot_spinner1").is_some());
+    /// assert!(Frames::by_name("unknown").is_none());
+    /// ```
+    pub fn by_name(name: &str) -> Option<Frames> {
+        if let Some(frames) = REGISTERED.read().unwrap().get(name) {
+            return Some(frames.clone());
+        }
+
+        PREDEFINED
+            .iter()
+            .find(|(predefined, _)| *predefined == name)
+            .map(|(_, constructor)| constructor())
+    }
+
+    /// returns the names of all animations
+    ///
+    /// the predefined animations come first (in the order they are defined),
+    /// followed by the registered animations in alphabetical order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::Frames;
+    ///
+    /// for name in Frames::all_names() {
+    ///     println!("{}", name);
+    /// }
+    /// # assert!(Frames::all_names().contains(&"clock".to_string()));
+    /// ```
+    pub fn all_names() -> Vec<String> {
+        let mut names = PREDEFINED
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+
+        for name in REGISTERED.read().unwrap().keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+
+        names
+    }
+
+    /// iterates over all predefined animations and their names
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::{Frames, MultiSpinner};
+    ///
+    /// let spinner = MultiSpinner::new();
+    ///
+    /// // preview every animation
+    /// for (name, frames) in Frames::predefined().take(5) {
+    ///     let uid = spinner.add(frames);
+    ///     spinner.set_text(&uid, name.to_string());
+    /// }
+    /// ```
+    pub fn predefined() -> impl Iterator<Item = (&'static str, Frames)> {
+        PREDEFINED
+            .iter()
+            .map(|(name, constructor)| (*name, constructor()))
+    }
+
+    /// registers an animation under a name, so it can be looked up with [`Frames::by_name`]
+    ///
+    /// registering a name of a predefined animation overrides it
+    ///
+    /// # Returns
+    ///
+    /// the animation previously registered under this name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zenity::spinner::Frames;
+    /// use zenity::styled_string;
+    /// use zenity::style::StyledString;
+    ///
+    /// Frames::register("traffic_light", Frames::generate_frames(styled_string!["🔴", "🟡", "🟢"], 300));
+    ///
+    /// let frames = Frames::by_name("traffic_light").unwrap();
+    /// # assert_eq!(frames.speed_ms, 300);
+    /// ```
+    pub fn register(name: &str, frames: Frames) -> Option<Frames> {
+        REGISTERED.write().unwrap().insert(name.to_string(), frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predefined_names_unique() {
+        let names = Frames::predefined()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        let mut unique = names.clone();
+        unique.sort_unstable();
+        unique.dedup();
+
+        assert_eq!(names.len(), unique.len());
+        assert!(names.len() >= 100);
+    }
+
+    #[test]
+    fn test_register() {
+        let custom = Frames {
+            speed_ms: 42,
+            ..Default::default()
+        };
+
+        assert!(Frames::register("test_register", custom).is_none());
+        assert_eq!(Frames::by_name("test_register").unwrap().speed_ms, 42);
+        assert_eq!(Frames::all_names().last().unwrap(), "test_register");
+
+        // registered animations take precedence over predefined ones
+        Frames::register(
+            "moon",
+            Frames {
+                speed_ms: 7,
+                ..Frames::moon()
+            },
+        );
+        assert_eq!(Frames::by_name("moon").unwrap().speed_ms, 7);
+        assert_eq!(
+            Frames::all_names()
+                .iter()
+                .filter(|name| *name == "moon")
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,415 @@
+//! state of the spinners in a [`MultiSpinner`](super::MultiSpinner) and their hierarchy
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::sort::SortBy;
+use crate::spinner::Frames;
+
+/// glyph drawn before a child that has siblings below it
+const BRANCH: &str = "├─ ";
+/// glyph drawn before the last child of a spinner
+const LAST_BRANCH: &str = "└─ ";
+/// indentation below a child that has siblings below it
+const PIPE: &str = "│  ";
+/// indentation below the last child of a spinner
+const SPACE: &str = "   ";
+
+/// status of a spinner
+///
+/// # Example
+///
+/// ```
+/// use zenity::spinner::{MultiSpinner, Status};
+///
+/// let spinner = MultiSpinner::default();
+/// let uid = spinner.get_last();
+///
+/// assert_eq!(spinner.status(&uid), Some(Status::Running));
+///
+/// spinner.succeed(&uid);
+/// assert_eq!(spinner.status(&uid), Some(Status::Succeeded));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// the spinner is animated
+    Running,
+    /// the spinner was stopped without a result
+    Stopped,
+    /// the spinner finished successfully
+    Succeeded,
+    /// the spinner (or one of its children) failed
+    Failed,
+}
+
+/// a spinner added to a `MultiSpinner`
+#[derive(Clone)]
+pub(crate) struct Task {
+    pub frames: Frames,
+    pub parent: Option<usize>,
+    pub status: Status,
+    started: Instant,
+    finished: Option<Instant>,
+}
+
+impl Task {
+    pub fn new(frames: Frames, parent: Option<usize>) -> Self {
+        let status = if frames.stop {
+            Status::Stopped
+        } else {
+            Status::Running
+        };
+
+        let started = Instant::now();
+
+        Task {
+            finished: frames.stop.then_some(started),
+            frames,
+            parent,
+            status,
+            started,
+        }
+    }
+
+    /// stops the animation and stores the result
+    ///
+    /// the timer freezes at the first call
+    pub fn finish(&mut self, status: Status) {
+        self.frames.stop = true;
+        self.status = status;
+        self.finished.get_or_insert_with(Instant::now);
+    }
+
+    /// restarts the timer if the task is still running
+    pub fn restart_timer(&mut self) {
+        if !self.is_finished() {
+            self.started = Instant::now();
+        }
+    }
+
+    pub fn finished_at(&self) -> Option<Instant> {
+        self.finished
+    }
+
+    /// time since the task was started, until it was finished
+    pub fn elapsed(&self) -> Duration {
+        self.finished
+            .unwrap_or_else(Instant::now)
+            .saturating_duration_since(self.started)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.frames.stop
+    }
+}
+
+/// spinners of a `MultiSpinner` by uid, in insertion order
+#[derive(Default)]
+pub(crate) struct Tasks {
+    map: HashMap<usize, Task>,
+    /// uids of the direct children of each spinner (`None` for the root spinners),
+    /// in insertion order
+    children: HashMap<Option<usize>, Vec<usize>>,
+}
+
+impl Tasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// appends a task with an incremental uid starting from 1
+    ///
+    /// # Returns
+    ///
+    /// the uid of the task
+    pub fn insert(&mut self, task: Task) -> usize {
+        let uid = self.map.len() + 1;
+
+        self.children.entry(task.parent).or_default().push(uid);
+        self.map.insert(uid, task);
+
+        uid
+    }
+
+    /// inserts a task as a sibling right before or after `anchor`
+    ///
+    /// the task is appended if the anchor doesn't exist
+    ///
+    /// # Returns
+    ///
+    /// the uid of the task
+    pub fn insert_next_to(&mut self, anchor: usize, after: bool, mut task: Task) -> usize {
+        let Some(parent) = self.map.get(&anchor).map(|anchor| anchor.parent) else {
+            return self.insert(task);
+        };
+
+        task.parent = parent;
+        let uid = self.insert(task);
+
+        // the anchor is a sibling, so it's in the same list the task was appended to
+        let siblings = self.children.get_mut(&parent).unwrap();
+        siblings.pop();
+        let index = siblings.iter().position(|uid| *uid == anchor).unwrap();
+        siblings.insert(if after { index + 1 } else { index }, uid);
+
+        uid
+    }
+
+    pub fn get(&self, uid: &usize) -> Option<&Task> {
+        self.map.get(uid)
+    }
+
+    pub fn get_mut(&mut self, uid: &usize) -> Option<&mut Task> {
+        self.map.get_mut(uid)
+    }
+
+    pub fn contains_key(&self, uid: &usize) -> bool {
+        self.map.contains_key(uid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Task> {
+        self.map.values_mut()
+    }
+
+    /// uids of the direct children of `parent` (or of the root spinners if `None`)
+    pub fn children(&self, parent: Option<usize>, sort: SortBy) -> Vec<usize> {
+        let mut children = self.children.get(&parent).cloned().unwrap_or_default();
+
+        // stable, so equal spinners keep their insertion order
+        match sort {
+            // spinners have no progress
+            SortBy::Insertion | SortBy::LeastProgress | SortBy::MostProgress => {}
+            SortBy::RunningFirst => children.sort_by_key(|uid| self.map[uid].is_finished()),
+            SortBy::FinishedFirst => children.sort_by_key(|uid| !self.map[uid].is_finished()),
+            SortBy::Name => children.sort_by(|a, b| {
+                self.map[a]
+                    .frames
+                    .text
+                    .string
+                    .cmp(&self.map[b].frames.text.string)
+            }),
+        }
+
+        children
+    }
+}
+
+/// formats a duration like `12.3s`, or like `2m 05s` from one minute on
+pub(crate) fn format_elapsed(elapsed: Duration) -> String {
+    // branch on the rounded value, so 59.96s doesn't show up as "60.0s"
+    let tenths = (elapsed.as_secs_f64() * 10.0).round() as u64;
+    let secs = tenths / 10;
+
+    if secs < 60 {
+        format!("{}.{}s", secs, tenths % 10)
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// status of a spinner including its children
+///
+/// a spinner counts as failed if any of its (nested) children failed
+pub(crate) fn aggregate_status(tasks: &Tasks, uid: usize) -> Option<Status> {
+    tasks.get(&uid)?;
+
+    Some(push_aggregate_status(tasks, uid, &mut HashMap::new()))
+}
+
+/// statuses of all spinners including their children, see [`aggregate_status`]
+///
+/// computed in a single pass over the tree, so rendering doesn't walk the subtree of every line
+pub(crate) fn aggregate_statuses(tasks: &Tasks) -> HashMap<usize, Status> {
+    let mut statuses = HashMap::with_capacity(tasks.len());
+
+    for root in tasks.children.get(&None).into_iter().flatten() {
+        push_aggregate_status(tasks, *root, &mut statuses);
+    }
+
+    statuses
+}
+
+/// stores the status of `uid` and its subtree after the statuses of its children
+fn push_aggregate_status(
+    tasks: &Tasks,
+    uid: usize,
+    statuses: &mut HashMap<usize, Status>,
+) -> Status {
+    let mut status = tasks.map[&uid].status;
+
+    for child in tasks.children.get(&Some(uid)).into_iter().flatten() {
+        if push_aggregate_status(tasks, *child, statuses) == Status::Failed {
+            status = Status::Failed;
+        }
+    }
+
+    statuses.insert(uid, status);
+    status
+}
+
+/// lays out the spinners as a tree
+///
+/// # Returns
+///
+/// the uid of each visible spinner in render order with the tree glyphs to draw before it,
+/// children of finished spinners are collapsed
+pub(crate) fn tree_lines(tasks: &Tasks, sort: SortBy) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    push_tree_lines(tasks, sort, None, "", &mut lines);
+
+    lines
+}
+
+fn push_tree_lines(
+    tasks: &Tasks,
+    sort: SortBy,
+    parent: Option<usize>,
+    indent: &str,
+    lines: &mut Vec<(usize, String)>,
+) {
+    let children = tasks.children(parent, sort);
+
+    for (index, uid) in children.iter().enumerate() {
+        let last = index + 1 == children.len();
+
+        // root spinners are drawn without glyphs
+        let (glyph, child_indent) = match (parent, last) {
+            (None, _) => ("", ""),
+            (Some(_), true) => (LAST_BRANCH, SPACE),
+            (Some(_), false) => (BRANCH, PIPE),
+        };
+
+        lines.push((*uid, format!("{}{}", indent, glyph)));
+
+        if !tasks.map[uid].is_finished() {
+            push_tree_lines(
+                tasks,
+                sort,
+                Some(*uid),
+                &format!("{}{}", indent, child_indent),
+                lines,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::StyledString;
+
+    fn tasks(parents: &[Option<usize>]) -> Tasks {
+        let mut tasks = Tasks::new();
+        for parent in parents {
+            tasks.insert(Task::new(Frames::default(), *parent));
+        }
+
+        tasks
+    }
+
+    #[test]
+    fn test_tree_lines() {
+        // 1
+        // ├─ 2
+        // │  └─ 4
+        // └─ 3
+        // 5
+        let tasks = tasks(&[None, Some(1), Some(1), Some(2), None]);
+
+        assert_eq!(
+            tree_lines(&tasks, SortBy::Insertion),
+            vec![
+                (1, "".to_string()),
+                (2, "├─ ".to_string()),
+                (4, "│  └─ ".to_string()),
+                (3, "└─ ".to_string()),
+                (5, "".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tree_lines_collapsed() {
+        let mut tasks = tasks(&[None, Some(1), Some(1)]);
+        tasks.get_mut(&1).unwrap().finish(Status::Succeeded);
+
+        assert_eq!(
+            tree_lines(&tasks, SortBy::Insertion),
+            vec![(1, "".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_insert_next_to() {
+        let mut tasks = tasks(&[None, Some(1), Some(1)]);
+
+        let before = tasks.insert_next_to(3, false, Task::new(Frames::default(), None));
+        let after = tasks.insert_next_to(1, true, Task::new(Frames::default(), None));
+        let missing = tasks.insert_next_to(42, true, Task::new(Frames::default(), None));
+
+        assert_eq!(tasks.get(&before).unwrap().parent, Some(1));
+        assert_eq!(tasks.children(Some(1), SortBy::Insertion), vec![2, 4, 3]);
+        assert_eq!(
+            tasks.children(None, SortBy::Insertion),
+            vec![1, after, missing]
+        );
+    }
+
+    #[test]
+    fn test_sorted_children() {
+        let mut tasks = tasks(&[None, None, None]);
+        tasks.get_mut(&1).unwrap().finish(Status::Succeeded);
+        for (uid, text) in [(1, "c"), (2, "a"), (3, "b")] {
+            tasks.get_mut(&uid).unwrap().frames.text = StyledString::new(text);
+        }
+
+        assert_eq!(tasks.children(None, SortBy::RunningFirst), vec![2, 3, 1]);
+        assert_eq!(tasks.children(None, SortBy::FinishedFirst), vec![1, 2, 3]);
+        assert_eq!(tasks.children(None, SortBy::Name), vec![2, 3, 1]);
+
+        // spinners have no progress, so these keep the insertion order
+        assert_eq!(tasks.children(None, SortBy::LeastProgress), vec![1, 2, 3]);
+        assert_eq!(tasks.children(None, SortBy::MostProgress), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_millis(0)), "0.0s");
+        assert_eq!(format_elapsed(Duration::from_millis(12_345)), "12.3s");
+        assert_eq!(format_elapsed(Duration::from_secs(125)), "2m 05s");
+        assert_eq!(format_elapsed(Duration::from_millis(59_940)), "59.9s");
+        assert_eq!(format_elapsed(Duration::from_millis(59_950)), "1m 00s");
+    }
+
+    #[test]
+    fn test_elapsed_freezes() {
+        let mut task = Task::new(Frames::default(), None);
+        task.finish(Status::Succeeded);
+
+        let elapsed = task.elapsed();
+        std::thread::sleep(Duration::from_millis(5));
+
+        assert_eq!(task.elapsed(), elapsed);
+    }
+
+    #[test]
+    fn test_aggregate_status() {
+        let mut tasks = tasks(&[None, Some(1), Some(2)]);
+        tasks.get_mut(&1).unwrap().finish(Status::Succeeded);
+        tasks.get_mut(&3).unwrap().finish(Status::Failed);
+
+        assert_eq!(aggregate_status(&tasks, 1), Some(Status::Failed));
+        assert_eq!(aggregate_status(&tasks, 2), Some(Status::Failed));
+        assert_eq!(aggregate_status(&tasks, 4), None);
+
+        let statuses = aggregate_statuses(&tasks);
+        assert_eq!(statuses[&1], Status::Failed);
+        assert_eq!(statuses[&2], Status::Failed);
+        assert_eq!(statuses[&3], Status::Failed);
+        assert_eq!(statuses.len(), 3);
+    }
+}
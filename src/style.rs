@@ -25,6 +25,11 @@
 //! ```
 
 pub use crossterm::style::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// string appended to text cut by [`StyledString::truncate`] and [`truncate_line`]
+pub const ELLIPSIS: &str = "…";
 
 /// combines multiple attributes into a single `style::Attributes` instance
 ///
@@ -101,10 +106,19 @@ pub fn combine_attributes(attr_list: &[&Attribute]) -> Attributes {
 /// # assert_eq!(styled.style, style);
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "crate::theme::StyledStringDef")
+)]
 pub struct StyledString {
     /// the string
     pub string: String,
     /// the ContentStyle to apply to the string
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::theme::content_style::serialize")
+    )]
     pub style: ContentStyle,
 }
 
@@ -145,6 +159,129 @@ impl StyledString {
     }
 }
 
+impl StyledString {
+    /// Returns the display width of the string in terminal columns
+    ///
+    /// wide characters (CJK, most emoji) count as two columns,
+    /// combining marks and zero width characters as none
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use zenity::style::StyledString;
+    ///
+    /// assert_eq!(StyledString::new("abc").width(), 3);
+    /// assert_eq!(StyledString::new("⚽").width(), 2);
+    /// assert_eq!(StyledString::new("日本").width(), 4);
+    /// ```
+    pub fn width(&self) -> usize {
+        self.string.width()
+    }
+
+    /// Truncates the string to fit into `max_width` columns
+    ///
+    /// if the string is too wide, it is cut and [`ELLIPSIS`] is appended,
+    /// the result including the ellipsis never exceeds `max_width`
+    ///
+    /// grapheme clusters are never split, so a wide character not fitting
+    /// into the remaining space is dropped as a whole
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use zenity::style::StyledString;
+    ///
+    /// let styled_text = StyledString::new("Hello, world!");
+    ///
+    /// assert_eq!(styled_text.truncate(8).string, "Hello, …");
+    /// assert_eq!(styled_text.truncate(20).string, "Hello, world!");
+    /// ```
+    pub fn truncate(&self, max_width: usize) -> StyledString {
+        truncate_line(std::slice::from_ref(self), max_width)
+            .pop()
+            .unwrap_or_else(|| StyledString {
+                string: String::new(),
+                style: self.style,
+            })
+    }
+}
+
+/// Returns the display width of a line made up of multiple StyledStrings
+///
+/// Example:
+///
+/// ```
+/// use zenity::style::{line_width, StyledString};
+/// use zenity::styled_string;
+///
+/// let line = styled_string!["⠋", " ", "Loading..."];
+/// assert_eq!(line_width(&line), 12);
+/// ```
+pub fn line_width(line: &[StyledString]) -> usize {
+    line.iter().map(StyledString::width).sum()
+}
+
+/// Truncates a line to fit into `max_width` columns
+///
+/// works like [`StyledString::truncate`] for the whole line,
+/// the [`ELLIPSIS`] keeps the style of the part it replaces
+///
+/// Example:
+///
+/// ```
+/// use zenity::style::{line_width, truncate_line, StyledString};
+/// use zenity::styled_string;
+///
+/// let line = styled_string!["(◕‿◕)", " ", "Loading..."];
+///
+/// let truncated = truncate_line(&line, 10);
+/// assert_eq!(line_width(&truncated), 10);
+/// assert_eq!(truncated[2].string, "Loa…");
+/// ```
+pub fn truncate_line(line: &[StyledString], max_width: usize) -> Vec<StyledString> {
+    if line_width(line) <= max_width {
+        return line.to_vec();
+    }
+
+    let mut remaining = max_width.saturating_sub(ELLIPSIS.width());
+    let mut truncated = Vec::new();
+
+    for styled_string in line {
+        let width = styled_string.width();
+        if width <= remaining {
+            truncated.push(styled_string.clone());
+            remaining -= width;
+            continue;
+        }
+
+        let mut string = String::new();
+        for grapheme in styled_string.string.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            if grapheme_width > remaining {
+                break;
+            }
+
+            string.push_str(grapheme);
+            remaining -= grapheme_width;
+        }
+
+        if max_width >= ELLIPSIS.width() {
+            string.push_str(ELLIPSIS);
+        }
+
+        // nothing fits into a width of 0, not even the ellipsis
+        if !string.is_empty() {
+            truncated.push(StyledString {
+                string,
+                style: styled_string.style,
+            });
+        }
+        break;
+    }
+
+    truncated
+}
+
 impl Default for StyledString {
     fn default() -> Self {
         StyledString::new("")
@@ -226,3 +363,45 @@ macro_rules! styled_string {
         ]
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_line() {
+        let content = vec![StyledString::new("Hello, "), StyledString::new("world")];
+
+        let truncated = truncate_line(&content, 9);
+        assert_eq!(truncated.len(), 2);
+        assert_eq!(truncated[1].string, "w…");
+
+        assert!(truncate_line(&content, 0).is_empty());
+    }
+
+    #[test]
+    fn test_truncate_line_ellipsis() {
+        let content = vec![StyledString::new("Hello, "), StyledString::new("world")];
+
+        // fitting lines are kept as they are
+        assert_eq!(truncate_line(&content, 12), content);
+
+        // the ellipsis replaces the end of the first part
+        let truncated = truncate_line(&content, 5);
+        assert_eq!(truncated.len(), 1);
+        assert_eq!(truncated[0].string, "Hell…");
+        assert_eq!(line_width(&truncated), 5);
+    }
+
+    #[test]
+    fn test_truncate_line_graphemes() {
+        // a wide character not fitting before the ellipsis is dropped as a whole
+        let wide = vec![StyledString::new("日本語")];
+        assert_eq!(truncate_line(&wide, 4)[0].string, "日…");
+        assert_eq!(truncate_line(&wide, 5)[0].string, "日本…");
+
+        // combining characters stay with their base character
+        let combined = vec![StyledString::new("e\u{301}e\u{301}e\u{301}")];
+        assert_eq!(truncate_line(&combined, 2)[0].string, "e\u{301}…");
+    }
+}
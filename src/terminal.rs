@@ -1,21 +1,59 @@
 pub(crate) mod console_render {
-    use std::io::{stdout, Write};
+    use std::env;
+    use std::io::{self, stdout, IsTerminal, Write};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use std::thread;
+    use std::time::Duration;
 
     use crossterm::style::Print;
     use crossterm::terminal::size;
     use crossterm::{cursor, execute, queue, style, terminal};
+    use lazy_static::lazy_static;
 
     use crate::color::ENABLE_COLOR;
-    use crate::style::StyledString;
+    use crate::style::{
+        combine_attributes, truncate_line, Attribute, Color, ContentStyle, StyledString,
+    };
+    use crate::terminal::console_guard;
 
-    macro_rules! raw_mode_wrapper {
-        ($content:expr) => {
-            enable_raw_mode().expect("Failed to enable raw-mode");
+    lazy_static! {
+        /// true if the terminal is known to support synchronized output (DEC mode 2026)
+        pub static ref SYNCHRONIZED_OUTPUT: bool =
+            stdout().is_terminal() && detect_synchronized_output(|key| env::var(key).ok());
+    }
 
-            $content;
+    /// checks the environment for terminals supporting synchronized output
+    ///
+    /// querying the mode (DECRQM) would require reading the terminal response,
+    /// which interferes with menus reading key events, so known terminals are matched instead
+    pub(crate) fn detect_synchronized_output(var: impl Fn(&str) -> Option<String>) -> bool {
+        const TERMS: [&str; 6] = [
+            "kitty",
+            "foot",
+            "wezterm",
+            "alacritty",
+            "contour",
+            "ghostty",
+        ];
+        const TERM_PROGRAMS: [&str; 5] = ["WezTerm", "iTerm.app", "vscode", "ghostty", "rio"];
 
-            disable_raw_mode().expect("Failed to disable raw-mode");
-        };
+        if var("ZENITY_SYNC_OUTPUT").is_some_and(|value| value == "0") {
+            return false;
+        }
+
+        let term_matches = var("TERM").is_some_and(|term| {
+            TERMS
+                .iter()
+                .any(|known| term.to_lowercase().contains(known))
+        });
+        let program_matches =
+            var("TERM_PROGRAM").is_some_and(|program| TERM_PROGRAMS.contains(&program.as_str()));
+
+        term_matches
+            || program_matches
+            || var("KITTY_WINDOW_ID").is_some()
+            || var("WT_SESSION").is_some()
     }
 
     macro_rules! push_styled_string {
@@ -43,69 +81,75 @@ pub(crate) mod console_render {
 
     pub(crate) use push_styled_string;
     pub(crate) use push_unstyled_spaces;
-    pub(crate) use raw_mode_wrapper;
 
-    pub fn render_line(frame: &Vec<String>, row: u16) {
-        let mut stdout = stdout();
-        queue!(
-            stdout,
-            cursor::RestorePosition,
-            cursor::MoveToNextLine(row + 1),
-        )
-        .unwrap();
+    /// queues the escapes drawing `content` at `row` of the live region into `out`
+    ///
+    /// nothing is written to the terminal until `out` is flushed
+    pub fn queue_styled_line(
+        out: &mut impl Write,
+        row: u16,
+        content: &[StyledString],
+    ) -> io::Result<()> {
+        if *ENABLE_COLOR {
+            queue_styled(out, row, content)
+        } else {
+            queue_unstyled(out, row, content)
+        }
+    }
 
-        for content in frame {
-            queue!(stdout, Print(content),).unwrap();
+    /// queues moving the cursor to the start of `row` of the live region
+    ///
+    /// the saved cursor position is the start of the first row
+    pub fn queue_move_to_row(out: &mut impl Write, row: u16) -> io::Result<()> {
+        queue!(out, cursor::RestorePosition)?;
+
+        // terminals treat a count of 0 like 1
+        if row > 0 {
+            queue!(out, cursor::MoveToNextLine(row))?;
         }
 
-        stdout.flush().unwrap();
+        Ok(())
     }
 
-    pub fn render_styled_line(row: u16, content: &[StyledString]) {
-        if *ENABLE_COLOR {
-            render_styled(row, content);
-        } else {
-            render_unstyled(row, content);
+    pub fn queue_unstyled(
+        out: &mut impl Write,
+        row: u16,
+        content: &[StyledString],
+    ) -> io::Result<()> {
+        queue_move_to_row(out, row)?;
+        queue!(out, terminal::Clear(terminal::ClearType::CurrentLine))?;
+
+        for content in content {
+            queue!(out, Print(&content.string))?;
         }
-    }
 
-    pub fn render_unstyled(row: u16, content: &[StyledString]) {
-        render_line(
-            &content
-                .iter()
-                .map(|styled_string| styled_string.string.clone())
-                .collect::<Vec<_>>(),
-            row,
-        );
+        Ok(())
     }
 
-    pub fn render_styled(row: u16, content: &[StyledString]) {
-        let mut stdout = stdout();
-        queue!(
-            stdout,
-            cursor::RestorePosition,
-            cursor::MoveToNextLine(row + 1), // move to the next line based on index +1
-            terminal::Clear(terminal::ClearType::CurrentLine),
-        )
-        .unwrap();
+    pub fn queue_styled(
+        out: &mut impl Write,
+        row: u16,
+        content: &[StyledString],
+    ) -> io::Result<()> {
+        queue_move_to_row(out, row)?;
+        queue!(out, terminal::Clear(terminal::ClearType::CurrentLine))?;
+
         for content in content {
             queue!(
-                stdout,
+                out,
                 style::SetStyle(content.style), // set animation color
                 Print(&content.string),
                 style::ResetColor, // reset colors
-            )
-            .unwrap();
+            )?;
         }
 
-        stdout.flush().unwrap();
+        Ok(())
     }
 
     pub fn cleanup() {
         execute!(
             stdout(),
             cursor::RestorePosition,
-            cursor::MoveToNextLine(2),
             terminal::Clear(terminal::ClearType::FromCursorDown),
         )
         .unwrap();
@@ -123,6 +167,380 @@ pub(crate) mod console_render {
         let (_cols, rows) = size().unwrap();
         rows
     }
+
+    /// returns the terminal size as (columns, rows)
+    ///
+    /// falls back to 80x24 if the size can't be determined
+    #[inline(always)]
+    pub fn get_size() -> (u16, u16) {
+        size().unwrap_or((80, 24))
+    }
+
+    /// rows of the terminal not used by the live region,
+    /// leaving room above it (see `console_cursor::save_hide_cursor`) and for the cursor below it
+    const RESERVED_ROWS: usize = 2;
+
+    /// the lines drawn starting at the saved cursor position
+    ///
+    /// keeps track of the terminal size so lines can be re-laid-out when the terminal is resized
+    /// and of the previously drawn frame so only changed lines are redrawn
+    ///
+    /// rows are reserved (scrolling the terminal if needed) whenever a frame is taller than
+    /// the rows reserved so far, so lines added later, like notes, never run off the screen
+    pub struct LiveRegion {
+        size: (u16, u16),
+        previous: Vec<Vec<StyledString>>,
+        synchronized: bool,
+        reanchor: bool,
+        /// rows known to exist below the saved position
+        reserved: u16,
+    }
+
+    impl LiveRegion {
+        pub fn new() -> Self {
+            LiveRegion {
+                size: get_size(),
+                previous: Vec::new(),
+                synchronized: *SYNCHRONIZED_OUTPUT,
+                reanchor: false,
+                reserved: 0,
+            }
+        }
+
+        /// moves the region below the current cursor position and redraws all lines on the next render
+        ///
+        /// used after the terminal was written to while the region was paused
+        pub fn reanchor(&mut self) {
+            self.reanchor = true;
+            self.previous.clear();
+        }
+
+        /// wraps each frame in begin/end synchronized update sequences if enabled
+        pub fn set_synchronized(&mut self, synchronized: bool) {
+            self.synchronized = synchronized;
+        }
+
+        /// samples the terminal size and clears the region if it changed
+        ///
+        /// # Returns
+        ///
+        /// the current terminal size as (columns, rows)
+        pub fn update_size(&mut self) -> (u16, u16) {
+            let size = get_size();
+
+            if size != self.size {
+                self.size = size;
+                self.previous.clear();
+                // rows may have been reflowed, so they are reserved again
+                self.reserved = 0;
+                cleanup();
+            }
+
+            size
+        }
+
+        /// renders the lines starting at the first row of the region
+        ///
+        /// lines are truncated to the terminal width and lines not fitting the terminal height
+        /// are collapsed into a single "+N more" line
+        ///
+        /// only lines that changed since the last call are redrawn,
+        /// and the whole frame is sent to the terminal in a single write
+        /// (wrapped in a synchronized update if enabled)
+        pub fn render(&mut self, lines: Vec<Vec<StyledString>>) {
+            let mut buffer = Vec::new();
+            self.queue_frame(&mut buffer, lines);
+
+            if !buffer.is_empty() {
+                let mut stdout = stdout().lock();
+                stdout.write_all(&buffer).unwrap();
+                stdout.flush().unwrap();
+            }
+        }
+
+        /// queues the escapes drawing the frame into `out`, see [`LiveRegion::render`]
+        pub(crate) fn queue_frame(&mut self, out: &mut impl Write, lines: Vec<Vec<StyledString>>) {
+            let (cols, rows) = self.size;
+
+            let lines = fit_height(lines, rows)
+                .iter()
+                .map(|line| truncate_line(line, cols as usize))
+                .collect::<Vec<_>>();
+            let height = lines.len() as u16;
+
+            let reserve = self.reanchor || height > self.reserved;
+            let changed = if reserve {
+                (0..self.previous.len().max(lines.len())).collect()
+            } else {
+                changed_lines(&self.previous, &lines)
+            };
+
+            if changed.is_empty() {
+                return;
+            }
+
+            if self.synchronized {
+                queue!(out, terminal::BeginSynchronizedUpdate).unwrap();
+            }
+
+            // the cursor is shown again by the menus after drawing
+            queue!(out, cursor::Hide).unwrap();
+
+            if reserve {
+                // reanchoring starts at the cursor, growing at the first line
+                if !std::mem::take(&mut self.reanchor) {
+                    queue!(out, cursor::RestorePosition).unwrap();
+                }
+
+                // make room for the lines (scrolling if needed) and save the start of the first line,
+                // moving back up by the same number of rows also follows the scrolled content
+                if height > 0 {
+                    queue!(
+                        out,
+                        Print("\n".repeat(height as usize)),
+                        cursor::MoveToPreviousLine(height),
+                    )
+                    .unwrap();
+                } else {
+                    queue!(out, cursor::MoveToColumn(0)).unwrap();
+                }
+
+                queue!(out, cursor::SavePosition).unwrap();
+                self.reserved = height;
+            }
+
+            for index in changed {
+                let line = lines.get(index).map(Vec::as_slice).unwrap_or_default();
+                queue_styled_line(out, index as u16, line).unwrap();
+            }
+
+            if self.synchronized {
+                queue!(out, terminal::EndSynchronizedUpdate).unwrap();
+            }
+
+            self.previous = lines;
+        }
+
+        /// moves the cursor to `column` of `line` of the last frame and shows it
+        ///
+        /// used by prompts to place the cursor in the typed text
+        pub fn show_cursor(&self, line: usize, column: u16) {
+            let mut stdout = stdout().lock();
+
+            queue_move_to_row(&mut stdout, line as u16).unwrap();
+            queue!(stdout, cursor::MoveToColumn(column), cursor::Show).unwrap();
+            stdout.flush().unwrap();
+        }
+    }
+
+    /// pause state shared between a container and its render loop
+    ///
+    /// the render loop holds the lock while drawing a frame,
+    /// so pausing waits for the current frame and no frame is drawn while paused
+    #[derive(Clone, Default)]
+    pub struct Pause(Arc<Mutex<PauseState>>);
+
+    #[derive(Default)]
+    pub struct PauseState {
+        /// no frames are drawn while paused
+        pub paused: bool,
+        /// set on resume, the region has to be moved below the output written while paused
+        pub resumed: bool,
+    }
+
+    impl Pause {
+        /// clears the live region and shows the cursor
+        ///
+        /// does nothing if already paused
+        pub fn pause(&self) {
+            let mut state = self.lock();
+            if state.paused {
+                return;
+            }
+
+            state.paused = true;
+
+            if console_guard::set_live_region(false) {
+                execute!(
+                    stdout(),
+                    cursor::RestorePosition,
+                    terminal::Clear(terminal::ClearType::FromCursorDown),
+                    cursor::Show,
+                )
+                .unwrap();
+            }
+        }
+
+        /// continues drawing frames below the current cursor position
+        ///
+        /// does nothing if not paused
+        pub fn resume(&self) {
+            let mut state = self.lock();
+            if !state.paused {
+                return;
+            }
+
+            state.paused = false;
+            state.resumed = true;
+
+            console_guard::set_live_region(true);
+        }
+
+        /// pauses while running `f`
+        ///
+        /// resumes even if `f` panics, so a caught panic doesn't leave the container hidden
+        pub fn suspend<T>(&self, f: impl FnOnce() -> T) -> T {
+            /// resumes on drop, including while unwinding
+            struct Resume<'a>(&'a Pause);
+
+            impl Drop for Resume<'_> {
+                fn drop(&mut self) {
+                    self.0.resume();
+                }
+            }
+
+            self.pause();
+            let _resume = Resume(self);
+
+            f()
+        }
+
+        /// locks the state, held by the render loop while drawing
+        pub fn lock(&self) -> MutexGuard<'_, PauseState> {
+            self.0.lock().unwrap()
+        }
+    }
+
+    /// the render loop of a container, started by `run_all` or `run_async`
+    ///
+    /// the loop owns the renderer, which restores the terminal when it's dropped at the end of the loop
+    #[derive(Clone, Default)]
+    pub struct RenderLoop {
+        stopped: Arc<AtomicBool>,
+        handle: Arc<Mutex<Option<LoopHandle>>>,
+    }
+
+    enum LoopHandle {
+        Thread(thread::JoinHandle<()>),
+        #[cfg(feature = "async")]
+        Task(Arc<tokio::sync::Notify>),
+    }
+
+    impl RenderLoop {
+        /// calls `render` on a new thread every `interval` until stopped
+        pub fn spawn_thread(&self, interval: Duration, mut render: impl FnMut() + Send + 'static) {
+            let stopped = Arc::clone(&self.stopped);
+
+            let thread = thread::spawn(move || {
+                // checked after waiting, so no frame is drawn once stopped
+                while !stopped.load(Ordering::SeqCst) {
+                    render();
+
+                    // unparked when stopped
+                    thread::park_timeout(interval);
+                }
+            });
+
+            *self.handle.lock().unwrap() = Some(LoopHandle::Thread(thread));
+        }
+
+        /// calls `render` in a task on `runtime` every `interval` until stopped
+        ///
+        /// the returned handle finishes after the terminal was restored
+        #[cfg(feature = "async")]
+        pub fn spawn_task(
+            &self,
+            runtime: &tokio::runtime::Handle,
+            interval: Duration,
+            mut render: impl FnMut() + Send + 'static,
+        ) -> tokio::task::JoinHandle<()> {
+            let stopped = Arc::clone(&self.stopped);
+            let notify = Arc::new(tokio::sync::Notify::new());
+            let wake = Arc::clone(&notify);
+
+            let task = runtime.spawn(async move {
+                // checked after waiting, so no frame is drawn once stopped
+                while !stopped.load(Ordering::SeqCst) {
+                    render();
+
+                    // notified when stopped
+                    let _ = tokio::time::timeout(interval, wake.notified()).await;
+                }
+            });
+
+            *self.handle.lock().unwrap() = Some(LoopHandle::Task(notify));
+
+            task
+        }
+
+        /// stops the loop
+        ///
+        /// waits for a render thread to draw its last frame and restore the terminal,
+        /// a render task is only woken up so this doesn't block the runtime
+        ///
+        /// # Returns
+        ///
+        /// true if a loop was running, which restores the terminal itself
+        pub fn stop(&self) -> bool {
+            self.stopped.store(true, Ordering::SeqCst);
+
+            match self.handle.lock().unwrap().take() {
+                Some(LoopHandle::Thread(thread)) => {
+                    thread.thread().unpark();
+                    let _ = thread.join();
+                    true
+                }
+                #[cfg(feature = "async")]
+                Some(LoopHandle::Task(notify)) => {
+                    notify.notify_one();
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    /// indices of the lines that differ between two frames
+    ///
+    /// lines only present in `previous` are included, so they get cleared
+    pub(crate) fn changed_lines(
+        previous: &[Vec<StyledString>],
+        current: &[Vec<StyledString>],
+    ) -> Vec<usize> {
+        (0..previous.len().max(current.len()))
+            .filter(|&index| previous.get(index) != current.get(index))
+            .collect()
+    }
+
+    /// limits the lines to the visible height, replacing the overflow with a "+N more" line
+    pub(crate) fn fit_height(
+        mut lines: Vec<Vec<StyledString>>,
+        rows: u16,
+    ) -> Vec<Vec<StyledString>> {
+        let max_lines = (rows as usize).saturating_sub(RESERVED_ROWS);
+
+        if lines.len() > max_lines {
+            let visible = max_lines.saturating_sub(1);
+            let hidden = lines.len() - visible;
+
+            lines.truncate(visible);
+
+            if max_lines > 0 {
+                let mut more = Vec::new();
+                push_styled_string!(
+                    more,
+                    format!("+{} more", hidden),
+                    Some(Color::Grey),
+                    None,
+                    None,
+                    combine_attributes(&[&Attribute::Italic])
+                );
+                lines.push(more);
+            }
+        }
+
+        lines
+    }
 }
 
 pub(crate) mod console_cursor {
@@ -130,21 +548,28 @@ pub(crate) mod console_cursor {
 
     use crossterm::{cursor, execute, terminal};
 
-    /// sets the cursor to be hidden, moves it to the next line,saves its current position,
-    /// and clears the terminal screen from the cursor position down
+    use crate::terminal::console_guard;
+
+    /// sets the cursor to be hidden, clears the terminal screen from the second row down
+    /// and saves the position the first line of the live region is drawn at, two rows below
     pub fn save_hide_cursor() {
+        console_guard::set_live_region(true);
+
         execute!(
             stdout(),
             cursor::MoveTo(0, 1),
             cursor::Hide,
-            cursor::SavePosition,
             terminal::Clear(terminal::ClearType::FromCursorDown),
+            cursor::MoveToNextLine(2),
+            cursor::SavePosition,
         )
         .unwrap();
     }
 
     /// resets the cursor to be shown and restores its saved position
     pub fn reset_cursor() {
+        console_guard::set_live_region(false);
+
         execute!(stdout(), cursor::RestorePosition, cursor::Show).unwrap();
     }
 
@@ -154,6 +579,50 @@ pub(crate) mod console_cursor {
     }
 }
 
+pub(crate) mod console_guard {
+    use std::io::{stdout, Write};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crossterm::terminal::{disable_raw_mode, is_raw_mode_enabled};
+    use crossterm::{cursor, queue, terminal};
+
+    /// true while a container owns the region below the saved cursor position
+    static LIVE_REGION: AtomicBool = AtomicBool::new(false);
+
+    /// marks the live region as active or inactive
+    /// # Returns
+    ///
+    /// true if a live region was active before
+    pub fn set_live_region(active: bool) -> bool {
+        LIVE_REGION.swap(active, Ordering::SeqCst)
+    }
+
+    /// returns the terminal to a usable state
+    ///
+    /// clears the live region (if any) and leaves the cursor below it,
+    /// disables raw mode and shows the cursor again
+    ///
+    /// errors are ignored as this runs inside panic hooks and signal handlers
+    pub fn restore() {
+        let mut stdout = stdout();
+
+        if LIVE_REGION.swap(false, Ordering::SeqCst) {
+            let _ = queue!(
+                stdout,
+                cursor::RestorePosition,
+                terminal::Clear(terminal::ClearType::FromCursorDown),
+            );
+        }
+
+        if is_raw_mode_enabled().unwrap_or(false) {
+            let _ = disable_raw_mode();
+        }
+
+        let _ = queue!(stdout, cursor::Show);
+        let _ = stdout.flush();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::style::Color;
@@ -168,7 +637,11 @@ mod tests {
             StyledString::simple(" world", Some(Color::Green), None, None),
         ];
 
-        console_render::render_unstyled(4, &content);
+        let mut buffer = Vec::new();
+        console_render::queue_unstyled(&mut buffer, 4, &content).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Hello,  world"));
     }
 
     #[test]
@@ -179,6 +652,170 @@ mod tests {
             StyledString::default(),
         ];
 
-        console_render::render_styled(4, &content);
+        let mut buffer = Vec::new();
+        console_render::queue_styled(&mut buffer, 4, &content).unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Hello, "));
+        assert!(output.contains(" world"));
+    }
+
+    #[test]
+    fn test_fit_height() {
+        let lines = vec![vec![StyledString::new("line")]; 10];
+
+        let fitted = console_render::fit_height(lines.clone(), 12);
+        assert_eq!(fitted, lines);
+
+        let fitted = console_render::fit_height(lines, 6);
+        assert_eq!(fitted.len(), 4);
+        assert_eq!(fitted[3][0].string, "+7 more");
+    }
+
+    #[test]
+    fn test_changed_lines() {
+        let previous = vec![
+            vec![StyledString::new("a")],
+            vec![StyledString::new("b")],
+            vec![StyledString::new("c")],
+        ];
+        let current = vec![vec![StyledString::new("a")], vec![StyledString::new("x")]];
+
+        assert_eq!(
+            console_render::changed_lines(&previous, &current),
+            vec![1, 2]
+        );
+        assert!(console_render::changed_lines(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_live_region_grow() {
+        let frame = |count: usize| vec![vec![StyledString::new("line")]; count];
+        let draw = |region: &mut console_render::LiveRegion, count: usize| {
+            let mut buffer = Vec::new();
+            region.queue_frame(&mut buffer, frame(count));
+            String::from_utf8(buffer).unwrap()
+        };
+
+        let mut region = console_render::LiveRegion::new();
+        region.set_synchronized(false);
+
+        // the first frame makes room for its lines below the cursor
+        let output = draw(&mut region, 2);
+        assert!(output.starts_with("\x1b[?25l\x1b8\n\n\x1b[2F\x1b7"));
+
+        // a frame of the same height only redraws the changed lines
+        assert!(draw(&mut region, 2).is_empty());
+        assert!(!draw(&mut region, 1).contains('\n'));
+
+        // a growing frame makes room again from the start of the region, scrolling if needed
+        let output = draw(&mut region, 4);
+        assert!(output.starts_with("\x1b[?25l\x1b8\n\n\n\n\x1b[4F\x1b7"));
+        assert_eq!(output.matches("line").count(), 4);
+
+        // reanchoring makes room at the cursor instead of the saved position
+        region.reanchor();
+        let output = draw(&mut region, 4);
+        assert!(output.starts_with("\x1b[?25l\n\n\n\n\x1b[4F\x1b7"));
+    }
+
+    #[test]
+    fn test_detect_synchronized_output() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                vars.iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        assert!(console_render::detect_synchronized_output(env(&[(
+            "TERM",
+            "xterm-kitty"
+        )])));
+        assert!(console_render::detect_synchronized_output(env(&[(
+            "TERM_PROGRAM",
+            "WezTerm"
+        )])));
+        assert!(!console_render::detect_synchronized_output(env(&[(
+            "TERM",
+            "xterm-256color"
+        )])));
+        assert!(!console_render::detect_synchronized_output(env(&[
+            ("TERM", "foot"),
+            ("ZENITY_SYNC_OUTPUT", "0")
+        ])));
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        let pause = console_render::Pause::default();
+
+        pause.resume();
+        assert!(!pause.lock().resumed);
+
+        pause.pause();
+        pause.pause();
+        assert!(pause.lock().paused);
+
+        pause.resume();
+        let state = pause.lock();
+        assert!(!state.paused);
+        assert!(state.resumed);
+    }
+
+    #[test]
+    fn test_render_loop_stop() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let render_loop = console_render::RenderLoop::default();
+        assert!(!render_loop.stop());
+
+        let render_loop = console_render::RenderLoop::default();
+        let frames = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&frames);
+        render_loop.spawn_thread(Duration::from_secs(60), move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // the thread is woken up instead of sleeping through the interval
+        assert!(render_loop.stop());
+        let drawn = frames.load(Ordering::SeqCst);
+        assert!(drawn <= 1);
+
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(frames.load(Ordering::SeqCst), drawn);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_render_loop_stop_task() {
+        use std::time::Duration;
+
+        let render_loop = console_render::RenderLoop::default();
+        let task = render_loop.spawn_task(
+            &tokio::runtime::Handle::current(),
+            Duration::from_secs(60),
+            || {},
+        );
+
+        // stopping doesn't wait for the task, which finishes right after being woken up
+        assert!(render_loop.stop());
+        tokio::time::timeout(Duration::from_secs(5), task)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_suspend_resumes_on_panic() {
+        let pause = console_render::Pause::default();
+
+        let result = std::panic::catch_unwind(|| pause.suspend(|| panic!("failed")));
+
+        assert!(result.is_err());
+        assert!(!pause.lock().paused);
     }
 }
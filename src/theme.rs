@@ -0,0 +1,259 @@
+//! Loading animations and styles from TOML or JSON files
+//!
+//! with the `serde` feature enabled, [`StyledString`], `spinner::Frames` and `progress::Frames`
+//! can be (de)serialized, so animations can be tweaked without recompiling
+//!
+//! styled strings can be written as plain strings or as tables with a style,
+//! missing fields of frames are taken from the default animation
+//!
+//! ```
+//! use zenity::spinner::Frames;
+//!
+//! let frames: Frames = zenity::theme::from_toml(r#"
+//!     frames = ["◐", "◓", "◑", { string = "◒", style = { foreground_color = "red", attributes = ["Bold"] } }]
+//!     speed_ms = 120
+//! "#).unwrap();
+//! # assert_eq!(frames.frames.len(), 4);
+//! # assert_eq!(frames.speed_ms, 120);
+//! # assert_eq!(frames.frames[3].style.foreground_color, Some(zenity::style::Color::Red));
+//! ```
+//!
+//! a theme holding multiple named animations is just a map:
+//!
+//! ```no_run
+//! use std::collections::HashMap;
+//! use zenity::spinner::Frames;
+//!
+//! let theme: HashMap<String, Frames> = zenity::theme::load("spinners.toml").unwrap();
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::style::{ContentStyle, StyledString};
+
+/// parses a value (e.g. `spinner::Frames`) from a TOML string
+///
+/// # Errors
+///
+/// an error of kind `InvalidData` if the content isn't valid
+pub fn from_toml<T: DeserializeOwned>(content: &str) -> io::Result<T> {
+    toml::from_str(content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// parses a value (e.g. `spinner::Frames`) from a JSON string
+///
+/// # Errors
+///
+/// an error of kind `InvalidData` if the content isn't valid
+///
+/// # Example
+///
+/// ```
+/// use zenity::progress::Frames;
+///
+/// let frames: Frames = zenity::theme::from_json(r#"{ "begin": ["<"], "end": [">"], "size": 20 }"#).unwrap();
+/// # assert_eq!(frames.size, Some(20));
+/// # assert_eq!(frames.begin[0].string, "<");
+/// ```
+pub fn from_json<T: DeserializeOwned>(content: &str) -> io::Result<T> {
+    serde_json::from_str(content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// reads a value (e.g. `spinner::Frames`) from a `.toml` or `.json` file
+///
+/// # Errors
+///
+/// - the error of reading the file
+/// - an error of kind `InvalidInput` if the extension is neither `toml` nor `json`
+/// - an error of kind `InvalidData` if the content isn't valid
+///
+/// # Example
+///
+/// ```no_run
+/// use zenity::spinner::Frames;
+///
+/// let frames: Frames = zenity::theme::load("spinner.json").unwrap();
+/// ```
+pub fn load<T: DeserializeOwned>(path: impl AsRef<Path>) -> io::Result<T> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path)?;
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => from_toml(&content),
+        Some("json") => from_json(&content),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported file type: {}", path.display()),
+        )),
+    }
+}
+
+/// (de)serializes a [`ContentStyle`] as a table of optional colors and a list of attributes
+///
+/// `ContentStyle` is defined by crossterm, use this module with `#[serde(with = "...")]`
+/// to store styles in your own types
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use zenity::style::ContentStyle;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "zenity::theme::content_style")]
+///     highlight: ContentStyle,
+/// }
+///
+/// let theme: Theme = zenity::theme::from_json(r#"{ "highlight": { "foreground_color": "cyan" } }"#).unwrap();
+/// # assert_eq!(theme.highlight.foreground_color, Some(zenity::style::Color::Cyan));
+/// ```
+pub mod content_style {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::style::{Attribute, Attributes, Color, ContentStyle};
+
+    #[derive(Default, Serialize, Deserialize)]
+    #[serde(default)]
+    struct Style {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        foreground_color: Option<Color>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        background_color: Option<Color>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        underline_color: Option<Color>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        attributes: Vec<Attribute>,
+    }
+
+    /// serializes a style, leaving out unset colors and attributes
+    pub fn serialize<S: Serializer>(
+        style: &ContentStyle,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Style {
+            foreground_color: style.foreground_color,
+            background_color: style.background_color,
+            underline_color: style.underline_color,
+            attributes: Attribute::iterator()
+                .filter(|attribute| style.attributes.has(*attribute))
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+
+    /// deserializes a style, missing colors and attributes are unset
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ContentStyle, D::Error> {
+        let style = Style::deserialize(deserializer)?;
+
+        Ok(ContentStyle {
+            foreground_color: style.foreground_color,
+            background_color: style.background_color,
+            underline_color: style.underline_color,
+            attributes: style
+                .attributes
+                .into_iter()
+                .fold(Attributes::default(), |attributes, attribute| {
+                    attributes | attribute
+                }),
+        })
+    }
+}
+
+/// a [`StyledString`] as written in files, either a plain string or a string with a style
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum StyledStringDef {
+    Plain(String),
+    Styled {
+        string: String,
+        #[serde(default, with = "content_style")]
+        style: ContentStyle,
+    },
+}
+
+impl From<StyledStringDef> for StyledString {
+    fn from(value: StyledStringDef) -> Self {
+        match value {
+            StyledStringDef::Plain(string) => StyledString::new(&string),
+            StyledStringDef::Styled { string, style } => StyledString { string, style },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Attribute, Color};
+
+    #[test]
+    fn test_styled_string_roundtrip() {
+        let mut styled =
+            StyledString::simple("◐", Some(Color::Rgb { r: 1, g: 2, b: 3 }), None, None);
+        styled.style.attributes.set(Attribute::Italic);
+
+        let json = serde_json::to_string(&styled).unwrap();
+        assert_eq!(
+            json,
+            r#"{"string":"◐","style":{"foreground_color":"rgb_(1,2,3)","attributes":["Italic"]}}"#
+        );
+        assert_eq!(from_json::<StyledString>(&json).unwrap(), styled);
+    }
+
+    #[test]
+    fn test_plain_styled_string() {
+        assert_eq!(
+            from_json::<StyledString>(r#""text""#).unwrap(),
+            StyledString::new("text")
+        );
+    }
+
+    #[cfg(feature = "spinner")]
+    #[test]
+    fn test_spinner_frames_roundtrip() {
+        let frames = crate::spinner::Frames::dot_spinner1();
+
+        let toml = toml::to_string(&frames).unwrap();
+        let parsed: crate::spinner::Frames = from_toml(&toml).unwrap();
+
+        assert_eq!(parsed.frames, frames.frames);
+        assert_eq!(parsed.speed_ms, frames.speed_ms);
+    }
+
+    #[cfg(feature = "spinner")]
+    #[test]
+    fn test_example_theme() {
+        let theme: std::collections::HashMap<String, crate::spinner::Frames> =
+            from_toml(include_str!("../examples/spinners.toml")).unwrap();
+
+        assert_eq!(theme["moon"].frames.len(), 8);
+        assert_eq!(
+            theme["pulse"].frames[1].style.foreground_color,
+            Some(Color::DarkRed)
+        );
+        assert!(theme["pulse"].elapsed);
+    }
+
+    #[cfg(feature = "progressbar")]
+    #[test]
+    fn test_progress_frames_roundtrip() {
+        let frames = crate::progress::Frames::rich();
+
+        let toml = toml::to_string(&frames).unwrap();
+        assert_eq!(from_toml::<crate::progress::Frames>(&toml).unwrap(), frames);
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        let err = from_toml::<StyledString>("string = ").unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}